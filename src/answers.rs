@@ -0,0 +1,229 @@
+use std::{collections::BTreeMap, fmt, fs};
+
+use crate::util::{Answer, Result};
+
+
+pub fn answers_path(year:u16) -> String {
+    format!("input/{}/answers.txt", year)
+}
+
+
+/// Confirmed answers for the real inputs, one `day part answer` line each. Answers the site rejected are kept
+/// as `day part answer wrong`, followed by `too high` or `too low` when the site said so. Lines starting with `#` are comments.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    entries:BTreeMap<(u8, u8), String>,
+    wrong:BTreeMap<(u8, u8), Vec<WrongAnswer>>,
+}
+
+/// A rejected answer and the hint that came with it.
+pub type WrongAnswer = (String, Option<Hint>);
+
+/// The direction the site gives with a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => f.pad("too high"),
+            Hint::TooLow => f.pad("too low"),
+        }
+    }
+}
+
+/// What the store knows about an answer before it is submitted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Known {
+    Correct,
+    /// Another answer is confirmed for the part.
+    Solved { expected:String },
+    /// Submitted before, or ruled out by a wrong answer that was too high or too low.
+    Wrong { hint:Option<Hint> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected:String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.pad("pass"),
+            Verdict::Fail { .. } => f.pad("fail"),
+            Verdict::Unknown => f.pad("unknown"),
+        }
+    }
+}
+
+
+impl Answers {
+    pub fn parse(text:&str) -> std::result::Result<Answers, String> {
+        let mut entries = BTreeMap::new();
+        let mut wrong = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields:Vec<&str> = line.split_whitespace().collect();
+            let key = match fields[..] {
+                [day, part, _, ..] => day.parse::<u8>().ok().zip(part.parse::<u8>().ok().filter(|part| *part == 1 || *part == 2)),
+                _ => None,
+            };
+            let (Some(key), Some(answer)) = (key, fields.get(2)) else {
+                return Err(format!("line {}: expected `day part answer`, found '{}'", index + 1, line));
+            };
+            match fields[3..] {
+                [] => { entries.insert(key, answer.to_string()); },
+                ["wrong"] => wrong.entry(key).or_insert_with(Vec::new).push((answer.to_string(), None)),
+                ["wrong", "too", "high"] => wrong.entry(key).or_insert_with(Vec::new).push((answer.to_string(), Some(Hint::TooHigh))),
+                ["wrong", "too", "low"] => wrong.entry(key).or_insert_with(Vec::new).push((answer.to_string(), Some(Hint::TooLow))),
+                _ => return Err(format!("line {}: expected `day part answer [wrong [too high|too low]]`, found '{}'", index + 1, line)),
+            }
+        }
+
+        Ok(Answers { entries, wrong })
+    }
+
+    /// A missing file is the same as an empty one.
+    pub fn load(path:&str) -> std::result::Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("failed to read {}: {}", path, e)),
+        }
+    }
+
+    pub fn save(&self, path:&str) -> std::io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day:u8, part:u8) -> Option<&str> {
+        self.entries.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, day:u8, part:u8, answer:&Answer) {
+        self.entries.insert((day, part), answer.to_string());
+    }
+
+    /// The answers the site rejected for a part, in the order they were recorded.
+    pub fn wrong(&self, day:u8, part:u8) -> &[WrongAnswer] {
+        self.wrong.get(&(day, part)).map_or(&[], |wrong| wrong.as_slice())
+    }
+
+    pub fn insert_wrong(&mut self, day:u8, part:u8, answer:&Answer, hint:Option<Hint>) {
+        let wrong = self.wrong.entry((day, part)).or_default();
+        let answer = answer.to_string();
+        wrong.retain(|(known, _)| *known != answer);
+        wrong.push((answer, hint));
+    }
+
+    /// Whether the site's verdict on `answer` is already known, so that it doesn't have to be submitted.
+    pub fn known(&self, day:u8, part:u8, answer:&Answer) -> Option<Known> {
+        let text = answer.to_string();
+        match self.get(day, part) {
+            Some(expected) if expected == text => return Some(Known::Correct),
+            Some(expected) => return Some(Known::Solved { expected: expected.to_string() }),
+            None => {},
+        }
+
+        let value = text.parse::<i128>().ok();
+        self.wrong(day, part).iter()
+            .find_map(|(known, hint)| {
+                if *known == text {
+                    return Some(Known::Wrong { hint: *hint });
+                }
+                let (value, known) = (value?, known.parse::<i128>().ok()?);
+                match hint {
+                    Some(Hint::TooHigh) if value >= known => Some(Known::Wrong { hint: Some(Hint::TooHigh) }),
+                    Some(Hint::TooLow) if value <= known => Some(Known::Wrong { hint: Some(Hint::TooLow) }),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Compares a solver result with the stored answer. Errors always fail, unsolved parts are unknown.
+    pub fn check(&self, day:u8, part:u8, result:&Result<Answer>) -> Verdict {
+        match (self.get(day, part), result) {
+            (_, Ok(Answer::Unsolved)) => Verdict::Unknown,
+            (Some(expected), Ok(answer)) if answer.to_string() == expected => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail { expected: expected.to_string() },
+            (None, Ok(_)) => Verdict::Unknown,
+            (None, Err(_)) => Verdict::Fail { expected: "-".to_string() },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part answer")?;
+        for ((day, part), answer) in &self.entries {
+            writeln!(f, "{} {} {}", day, part, answer)?;
+        }
+        for ((day, part), wrong) in &self.wrong {
+            for (answer, hint) in wrong {
+                match hint {
+                    Some(hint) => writeln!(f, "{} {} {} wrong {}", day, part, answer, hint)?,
+                    None => writeln!(f, "{} {} {} wrong", day, part, answer)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::Error;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day part answer\n1 1 2344935\n\n17 1 1,3,7\n").unwrap();
+        assert_eq!(Some("2344935"), answers.get(1, 1));
+        assert_eq!(Some("1,3,7"), answers.get(17, 1));
+        assert_eq!(None, answers.get(1, 2));
+        assert_eq!("# day part answer\n1 1 2344935\n17 1 1,3,7\n", answers.to_string());
+
+        assert_eq!(Err("line 2: expected `day part answer`, found '1 3 5'".to_string()), Answers::parse("1 1 5\n1 3 5"));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.insert(18, 2, &Answer::Coord(31, 22));
+
+        assert_eq!(Verdict::Pass, answers.check(18, 2, &Ok(Answer::Coord(31, 22))));
+        assert_eq!(Verdict::Fail { expected: "31,22".to_string() }, answers.check(18, 2, &Ok(Answer::Coord(22, 31))));
+        assert_eq!(Verdict::Fail { expected: "31,22".to_string() }, answers.check(18, 2, &Err(Error::solve("no path"))));
+        assert_eq!(Verdict::Unknown, answers.check(18, 1, &Ok(Answer::Int(294))));
+        assert_eq!(Verdict::Unknown, answers.check(18, 2, &Ok(Answer::Unsolved)));
+    }
+
+    #[test]
+    fn test_known() {
+        let mut answers = Answers::parse("12 1 1473408\n12 2 883914 wrong too low\n12 2 900000 wrong\n").unwrap();
+        assert_eq!(&[("883914".to_string(), Some(Hint::TooLow)), ("900000".to_string(), None)], answers.wrong(12, 2));
+        assert_eq!("# day part answer\n12 1 1473408\n12 2 883914 wrong too low\n12 2 900000 wrong\n", answers.to_string());
+
+        assert_eq!(Some(Known::Correct), answers.known(12, 1, &Answer::Int(1473408)));
+        assert_eq!(Some(Known::Solved { expected: "1473408".to_string() }), answers.known(12, 1, &Answer::Int(5)));
+        assert_eq!(Some(Known::Wrong { hint: Some(Hint::TooLow) }), answers.known(12, 2, &Answer::Int(800000)));
+        assert_eq!(Some(Known::Wrong { hint: None }), answers.known(12, 2, &Answer::Int(900000)));
+        assert_eq!(None, answers.known(12, 2, &Answer::Int(950000)));
+
+        answers.insert_wrong(12, 2, &Answer::Int(950000), Some(Hint::TooHigh));
+        assert_eq!(Some(Known::Wrong { hint: Some(Hint::TooHigh) }), answers.known(12, 2, &Answer::Int(960000)));
+        assert!(Answers::parse("12 2 5 right").is_err());
+    }
+}
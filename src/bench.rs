@@ -0,0 +1,204 @@
+use std::{fs, io::Write, time::{Duration, Instant, SystemTime, UNIX_EPOCH}};
+
+use crate::runner::{format_duration, input_path, read_input};
+use crate::util::{Part, Puzzle};
+
+
+pub const WARMUP_RUNS:usize = 3;
+pub const DEFAULT_RUNS:usize = 20;
+/// A median this much slower than the previous baseline is reported as a regression.
+pub const REGRESSION_THRESHOLD:f64 = 0.10;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs:usize,
+    pub min:Duration,
+    pub median:Duration,
+    pub mean:Duration,
+    pub stddev:Duration,
+    pub p95:Duration,
+}
+
+impl Stats {
+    /// `samples` must not be empty.
+    pub fn from_samples(samples:&[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / runs as f64;
+        let variance = sorted.iter().map(|d| (d.as_secs_f64() - mean).powi(2)).sum::<f64>() / runs as f64;
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+        // Nearest rank
+        let p95 = sorted[((runs as f64 * 0.95).ceil() as usize).clamp(1, runs) - 1];
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95,
+        }
+    }
+}
+
+
+pub fn history_path(year:u16) -> String {
+    format!("bench/{}/history.txt", year)
+}
+
+
+/// One line of the history file of a year: `timestamp day part runs min median mean stddev p95`, durations in nanoseconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub timestamp:u64,
+    pub day:u8,
+    pub part:u8,
+    pub stats:Stats,
+}
+
+impl HistoryEntry {
+    pub fn parse(line:&str) -> Option<HistoryEntry> {
+        let fields:Vec<u64> = line.split_whitespace().map(|field| field.parse::<u64>().ok()).collect::<Option<_>>()?;
+        if fields.len() != 9 {
+            return None;
+        }
+        let nanos = Duration::from_nanos;
+        Some(HistoryEntry {
+            timestamp: fields[0],
+            day: u8::try_from(fields[1]).ok()?,
+            part: u8::try_from(fields[2]).ok()?,
+            stats: Stats {
+                runs: fields[3] as usize,
+                min: nanos(fields[4]),
+                median: nanos(fields[5]),
+                mean: nanos(fields[6]),
+                stddev: nanos(fields[7]),
+                p95: nanos(fields[8]),
+            },
+        })
+    }
+
+    pub fn to_line(&self) -> String {
+        let s = &self.stats;
+        format!("{} {} {} {} {} {} {} {} {}", self.timestamp, self.day, self.part, s.runs,
+            s.min.as_nanos(), s.median.as_nanos(), s.mean.as_nanos(), s.stddev.as_nanos(), s.p95.as_nanos())
+    }
+}
+
+fn load_history(path:&str) -> Vec<HistoryEntry> {
+    fs::read_to_string(path)
+        .map(|text| text.lines().filter_map(HistoryEntry::parse).collect())
+        .unwrap_or_default()
+}
+
+fn append_history(path:&str, entry:&HistoryEntry) -> std::io::Result<()> {
+    if let Some(dir) = std::path::Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", entry.to_line())
+}
+
+/// Relative change of the median against `baseline`, positive means slower.
+pub fn change(baseline:&Stats, current:&Stats) -> f64 {
+    current.median.as_secs_f64() / baseline.median.as_secs_f64() - 1.0
+}
+
+
+fn bench_part(solver:&dyn Puzzle, input:&str, part:Part, runs:usize) -> Result<Stats, String> {
+    for _ in 0..WARMUP_RUNS {
+        solver.solve(input, part).map_err(|e| e.to_string())?;
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        solver.solve(input, part).map_err(|e| e.to_string())?;
+        samples.push(start.elapsed());
+    }
+
+    Ok(Stats::from_samples(&samples))
+}
+
+/// Benchmarks the given parts on the stored input, prints the statistics and records them in the history file.
+pub fn bench(year:u16, solver:&dyn Puzzle, parts:&[Part], runs:usize) -> Result<(), String> {
+    let input = read_input(&input_path(year, solver.day()))?;
+    let history_path = history_path(year);
+    let history = load_history(&history_path);
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+    println!("{} day {} {} ({} warmup runs, {} runs)", year, solver.day(), solver.title(), WARMUP_RUNS, runs);
+    println!("{:>4} {:>10} {:>10} {:>10} {:>10} {:>10}  Baseline", "Part", "Min", "Median", "Mean", "Stddev", "P95");
+
+    for part in parts {
+        let part_number = part.number();
+        let stats = bench_part(solver, &input, *part, runs)?;
+
+        let baseline = history.iter().rev()
+            .find(|entry| entry.day == solver.day() && entry.part == part_number);
+        let comparison = match baseline {
+            Some(baseline) => {
+                let change = change(&baseline.stats, &stats);
+                let flag = if change > REGRESSION_THRESHOLD { "  REGRESSION" } else { "" };
+                format!("{:+.1}% vs {}{}", change * 100.0, format_duration(baseline.stats.median), flag)
+            },
+            None => "none".to_string(),
+        };
+
+        println!("{:>4} {:>10} {:>10} {:>10} {:>10} {:>10}  {}", part_number,
+            format_duration(stats.min), format_duration(stats.median), format_duration(stats.mean),
+            format_duration(stats.stddev), format_duration(stats.p95), comparison);
+
+        let entry = HistoryEntry { timestamp, day: solver.day(), part: part_number, stats };
+        append_history(&history_path, &entry).map_err(|e| format!("failed to write {}: {}", history_path, e))?;
+    }
+
+    Ok(())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values:&[u64]) -> Vec<Duration> {
+        values.iter().map(|value| Duration::from_millis(*value)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(5, stats.runs);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(3), stats.mean);
+        assert_eq!(Duration::from_millis(5), stats.p95);
+        assert!((stats.stddev.as_secs_f64() - 2.0_f64.sqrt() / 1000.0).abs() < 1e-9);
+
+        let stats = Stats::from_samples(&millis(&[4, 1, 3, 2]));
+        assert_eq!(Duration::from_micros(2500), stats.median);
+    }
+
+    #[test]
+    fn test_history_line() {
+        let entry = HistoryEntry { timestamp: 1700000000, day: 11, part: 2, stats: Stats::from_samples(&millis(&[2, 4])) };
+        assert_eq!("1700000000 11 2 2 2000000 3000000 3000000 1000000 4000000", entry.to_line());
+        assert_eq!(Some(entry.clone()), HistoryEntry::parse(&entry.to_line()));
+        assert_eq!(None, HistoryEntry::parse("1700000000 11 2"));
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = Stats::from_samples(&millis(&[10]));
+        assert!(change(&baseline, &Stats::from_samples(&millis(&[12]))) > REGRESSION_THRESHOLD);
+        assert!(change(&baseline, &Stats::from_samples(&millis(&[9]))) < 0.0);
+    }
+}
@@ -0,0 +1,399 @@
+use std::{env, fs, io::Write, path::Path, process::{Command, Stdio}, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use crate::answers::Hint;
+
+
+pub const DEFAULT_BASE_URL:&str = "https://adventofcode.com";
+/// `key = value` lines with the session token and optionally another base URL. Keep it out of git.
+pub const CONFIG_PATH:&str = "aoc.config";
+/// The unix time in seconds when the site takes answers again after throttling. Keep it out of git.
+pub const COOLDOWN_PATH:&str = "aoc.cooldown";
+pub const SESSION_VAR:&str = "AOC_SESSION";
+pub const BASE_URL_VAR:&str = "AOC_BASE_URL";
+/// The site asks automated tools to say who they are.
+const USER_AGENT:&str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"), " (", env!("CARGO_PKG_AUTHORS"), ")");
+
+
+/// Where to reach the website and who to be there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub session:Option<String>,
+    pub base_url:String,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { session: None, base_url: DEFAULT_BASE_URL.to_string() }
+    }
+}
+
+impl Config {
+    pub fn parse(text:&str) -> Result<Config, String> {
+        let mut config = Config::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match line.split_once('=').map(|(key, value)| (key.trim(), value.trim())) {
+                Some(("session", value)) => config.session = Some(value.to_string()),
+                Some(("base_url", value)) => config.base_url = value.trim_end_matches('/').to_string(),
+                _ => return Err(format!("line {}: expected `session = <token>` or `base_url = <url>`, found '{}'", index + 1, line)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Reads `path`, a missing file is the same as an empty one. `AOC_SESSION` and `AOC_BASE_URL` override the file.
+    pub fn load(path:&str) -> Result<Config, String> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text).map_err(|e| format!("{}: {}", path, e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(format!("failed to read {}: {}", path, e)),
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = base_url.trim_end_matches('/').to_string();
+        }
+        Ok(config)
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref()
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| format!("no session token, set {} or `session` in {}", SESSION_VAR, CONFIG_PATH))
+    }
+
+    pub fn input_url(&self, year:u16, day:u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn answer_url(&self, year:u16, day:u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status:u16,
+    pub body:String,
+}
+
+/// The HTTP requests the client needs. `Curl` talks to the real site, tests swap in their own.
+pub trait Http {
+    fn get(&self, url:&str, session:&str) -> Result<Response, String>;
+
+    /// Posts `form` url encoded.
+    fn post(&self, url:&str, session:&str, form:&[(&str, &str)]) -> Result<Response, String>;
+}
+
+/// Runs the `curl` binary, which handles TLS so that the crate doesn't have to.
+pub struct Curl;
+
+impl Curl {
+    fn run(&self, args:&[String], url:&str, session:&str) -> Result<Response, String> {
+        // The cookie goes through stdin so that it doesn't show up in the process list
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--header", "@-", "--user-agent", USER_AGENT, "--write-out", "\n%{http_code}"])
+            .args(args)
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+        child.stdin.take()
+            .expect("stdin is piped")
+            .write_all(format!("Cookie: session={}\n", session).as_bytes())
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+
+        let output = child.wait_with_output().map_err(|e| format!("failed to run curl: {}", e))?;
+        if !output.status.success() {
+            return Err(format!("curl failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+        let status = status.trim().parse::<u16>().map_err(|_| format!("curl returned no status code for {}", url))?;
+        Ok(Response { status, body: body.to_string() })
+    }
+}
+
+impl Http for Curl {
+    fn get(&self, url:&str, session:&str) -> Result<Response, String> {
+        self.run(&[], url, session)
+    }
+
+    fn post(&self, url:&str, session:&str, form:&[(&str, &str)]) -> Result<Response, String> {
+        let args:Vec<String> = form.iter()
+            .flat_map(|(key, value)| ["--data-urlencode".to_string(), format!("{}={}", key, value)])
+            .collect();
+        self.run(&args, url, session)
+    }
+}
+
+/// Turns the common failures into messages, the site answers with a login page when the session is wrong.
+fn check_status(url:&str, response:&Response) -> Result<(), String> {
+    match response.status {
+        200 => Ok(()),
+        404 => Err(format!("{} was not found, the puzzle may not be available yet", url)),
+        400 | 401 | 500 if response.body.contains("log in") => Err("the session token was rejected, it may have expired".to_string()),
+        status => Err(format!("{} returned HTTP {}", url, status)),
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the input of `day` of `year` to `path`, unless `path` already has an input. Cached inputs are never fetched
+/// again, an empty file is a placeholder and gets replaced.
+pub fn fetch_input(http:&dyn Http, config:&Config, year:u16, day:u8, path:&str) -> Result<Fetched, String> {
+    if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let url = config.input_url(year, day);
+    let response = http.get(&url, config.session()?)?;
+    if response.status == 404 {
+        return Err(format!("the input of day {} is not available yet", day));
+    }
+    check_status(&url, &response)?;
+    if response.body.is_empty() {
+        return Err(format!("{} returned an empty input", url));
+    }
+
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    }
+    fs::write(path, response.body).map_err(|e| format!("failed to write {}: {}", path, e))?;
+    Ok(Fetched::Downloaded)
+}
+
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Wrong { hint:Option<Hint> },
+    /// Too many answers too quickly, with the time left to wait when the site says.
+    Throttled { wait:Option<Duration> },
+    /// The part was solved before, the site doesn't check answers for solved parts.
+    AlreadySolved,
+}
+
+/// Reads the verdict from the text of the answer page.
+pub fn parse_outcome(body:&str) -> Result<Outcome, String> {
+    if body.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if body.contains("That's not the right answer") {
+        let hint = if body.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if body.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Ok(Outcome::Wrong { hint })
+    } else if body.contains("You gave an answer too recently") {
+        Ok(Outcome::Throttled { wait: parse_wait(body) })
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::AlreadySolved)
+    } else {
+        Err("could not find a verdict in the response".to_string())
+    }
+}
+
+/// Parses `You have 1m 13s left to wait`.
+fn parse_wait(body:&str) -> Option<Duration> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|item| match item.split_at(item.len().saturating_sub(1)) {
+            (minutes, "m") => minutes.parse::<u64>().ok().map(|minutes| minutes * 60),
+            (seconds, "s") => seconds.parse::<u64>().ok(),
+            _ => None,
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// The wait when the site throttles without saying for how long, its shortest wait after a wrong answer.
+pub const DEFAULT_WAIT:Duration = Duration::from_secs(60);
+
+/// Records at `path` that the site takes answers again `wait` after `now`.
+pub fn start_cooldown(path:&str, now:SystemTime, wait:Duration) -> Result<(), String> {
+    let until = now.duration_since(UNIX_EPOCH).unwrap_or_default() + wait;
+    fs::write(path, format!("{}\n", until.as_secs())).map_err(|e| format!("failed to write {}: {}", path, e))
+}
+
+/// The time left to wait before submitting at `now`, `None` when there is no cooldown at `path` or it has passed.
+pub fn cooldown_left(path:&str, now:SystemTime) -> Result<Option<Duration>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("failed to read {}: {}", path, e)),
+    };
+    let until = text.trim().parse::<u64>()
+        .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+        .map_err(|_| format!("{}: expected a unix time in seconds, found '{}'", path, text.trim()))?;
+    Ok(until.duration_since(now).ok().filter(|left| !left.is_zero()))
+}
+
+pub fn submit_answer(http:&dyn Http, config:&Config, year:u16, day:u8, part:u8, answer:&str) -> Result<Outcome, String> {
+    let url = config.answer_url(year, day);
+    let level = part.to_string();
+    let response = http.post(&url, config.session()?, &[("level", &level), ("answer", answer)])?;
+    check_status(&url, &response)?;
+    parse_outcome(&response.body)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, io::{BufRead, BufReader}, net::TcpListener, thread};
+
+    /// Answers every request with the same response and remembers the urls.
+    struct Stub {
+        response:Response,
+        requests:RefCell<Vec<String>>,
+    }
+
+    impl Http for Stub {
+        fn get(&self, url:&str, session:&str) -> Result<Response, String> {
+            self.requests.borrow_mut().push(format!("GET {} {}", url, session));
+            Ok(self.response.clone())
+        }
+
+        fn post(&self, url:&str, session:&str, form:&[(&str, &str)]) -> Result<Response, String> {
+            self.requests.borrow_mut().push(format!("POST {} {} {:?}", url, session, form));
+            Ok(self.response.clone())
+        }
+    }
+
+    fn stub(status:u16, body:&str) -> Stub {
+        Stub { response: Response { status, body: body.to_string() }, requests: RefCell::default() }
+    }
+
+    fn temp_path(name:&str) -> String {
+        let dir = env::temp_dir().join(format!("aoc_client_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("input_03.txt").to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_config() {
+        let config = Config::parse("# aoc\nsession = abc123\nbase_url = http://localhost:8080/\n").unwrap();
+        assert_eq!(Some("abc123".to_string()), config.session);
+        assert_eq!("http://localhost:8080/2024/day/3/input", config.input_url(2024, 3));
+        assert_eq!(DEFAULT_BASE_URL, Config::parse("").unwrap().base_url);
+        assert!(Config::parse("token abc").is_err());
+    }
+
+    #[test]
+    fn test_fetch() {
+        let config = Config { session: Some("abc123".to_string()), base_url: "http://stub".to_string() };
+        let path = temp_path("fetch");
+        let http = stub(200, "xmul(2,4)\n");
+
+        fs::create_dir_all(Path::new(&path).parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+        assert_eq!(Ok(Fetched::Downloaded), fetch_input(&http, &config, 2024, 3, &path));
+        assert_eq!("xmul(2,4)\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(Ok(Fetched::Cached), fetch_input(&http, &config, 2024, 3, &path));
+        assert_eq!(vec!["GET http://stub/2024/day/3/input abc123".to_string()], *http.requests.borrow());
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let config = Config { session: Some("abc123".to_string()), base_url: "http://stub".to_string() };
+        let path = temp_path("errors");
+
+        let error = fetch_input(&stub(404, "Not Found"), &config, 2024, 3, &path);
+        assert_eq!(Err("the input of day 3 is not available yet".to_string()), error);
+        let error = fetch_input(&stub(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."), &config, 2024, 3, &path);
+        assert_eq!(Err("the session token was rejected, it may have expired".to_string()), error);
+        assert!(!Path::new(&path).exists());
+
+        let http = stub(200, "xmul(2,4)\n");
+        assert!(fetch_input(&http, &Config::default(), 2024, 3, &path).is_err());
+        assert!(http.requests.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_outcome() {
+        let wrong = "<article><p>That's not the right answer; your answer is too low.  If you're stuck, ...";
+        assert_eq!(Ok(Outcome::Wrong { hint: Some(Hint::TooLow) }), parse_outcome(wrong));
+        assert_eq!(Ok(Outcome::Correct), parse_outcome("<article><p>That's the right answer!  You are one gold star closer"));
+        let throttled = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 13s left to wait.";
+        assert_eq!(Ok(Outcome::Throttled { wait: Some(Duration::from_secs(73)) }), parse_outcome(throttled));
+        assert_eq!(Ok(Outcome::AlreadySolved), parse_outcome("You don't seem to be solving the right level.  Did you already complete it?"));
+        assert!(parse_outcome("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let path = temp_path("cooldown");
+        let now = UNIX_EPOCH + Duration::from_secs(1_733_000_000);
+        assert_eq!(Ok(None), cooldown_left(&path, now));
+
+        fs::create_dir_all(Path::new(&path).parent().unwrap()).unwrap();
+        start_cooldown(&path, now, Duration::from_secs(73)).unwrap();
+        assert_eq!("1733000073\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(Ok(Some(Duration::from_secs(73))), cooldown_left(&path, now));
+        assert_eq!(Ok(Some(Duration::from_secs(1))), cooldown_left(&path, now + Duration::from_secs(72)));
+        assert_eq!(Ok(None), cooldown_left(&path, now + Duration::from_secs(73)));
+        assert_eq!(Ok(None), cooldown_left(&path, now + Duration::from_secs(3600)));
+
+        fs::write(&path, "soon").unwrap();
+        assert!(cooldown_left(&path, now).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let config = Config { session: Some("abc123".to_string()), base_url: "http://stub".to_string() };
+        let http = stub(200, "<article><p>That's not the right answer; your answer is too high.");
+
+        assert_eq!(Ok(Outcome::Wrong { hint: Some(Hint::TooHigh) }), submit_answer(&http, &config, 2024, 12, 2, "883914"));
+        assert_eq!(vec!["POST http://stub/2024/day/12/answer abc123 [(\"level\", \"2\"), (\"answer\", \"883914\")]".to_string()], *http.requests.borrow());
+        assert!(submit_answer(&stub(400, "Please log in"), &config, 2024, 12, 2, "883914").is_err());
+    }
+
+    #[test]
+    fn test_curl() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request.push(line.trim().to_string());
+            }
+            write!(&stream, "HTTP/1.1 200 OK\r\nContent-Length: 10\r\nConnection: close\r\n\r\nxmul(2,4)\n").unwrap();
+            request
+        });
+
+        let response = Curl.get(&format!("{}/2024/day/3/input", base_url), "abc123").unwrap();
+        assert_eq!(Response { status: 200, body: "xmul(2,4)\n".to_string() }, response);
+
+        let request = server.join().unwrap();
+        assert_eq!("GET /2024/day/3/input HTTP/1.1", request[0]);
+        assert!(request.contains(&"Cookie: session=abc123".to_string()));
+    }
+}
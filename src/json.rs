@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use crate::answers::{Answers, Verdict};
+use crate::runner::{DayResult, PartResult};
+use crate::util::{Answer, Result};
+
+
+/// Quotes and escapes `text` as a JSON string.
+pub fn string(text:&str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The largest integer a double holds exactly, 2^53 - 1.
+const MAX_SAFE_INTEGER:i64 = (1 << 53) - 1;
+
+/// An integer as a JSON number, or as a string when a double can't hold it exactly.
+fn integer(value:i64) -> String {
+    if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value) {
+        value.to_string()
+    } else {
+        string(&value.to_string())
+    }
+}
+
+/// The answer as a JSON value. Integers beyond 2^53 are strings, they don't fit in the doubles most JSON readers use.
+pub fn answer(answer:&Answer) -> String {
+    match answer {
+        Answer::Int(value) => integer(*value),
+        Answer::BigInt(value) => string(&value.to_string()),
+        Answer::Text(text) => string(text),
+        Answer::List(values) => {
+            let items:Vec<String> = values.iter().map(|value| integer(*value)).collect();
+            format!("[{}]", items.join(","))
+        },
+        Answer::Coord(x, y) => format!("[{},{}]", integer(*x), integer(*y)),
+        Answer::Unsolved => "null".to_string(),
+    }
+}
+
+/// One part as a JSON object. Without `answers` the input is not the stored one, so the status is unknown.
+pub fn record(year:u16, day:u8, part:u8, result:&Result<Answer>, elapsed:Duration, answers:Option<&Answers>) -> String {
+    let verdict = answers.map_or(Verdict::Unknown, |answers| answers.check(day, part, result));
+    let (value, kind, error) = match result {
+        Ok(value) => (answer(value), string(value.kind()), String::new()),
+        Err(error) => ("null".to_string(), "null".to_string(), format!(",\"error\":{}", string(&error.to_string()))),
+    };
+    format!("{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"type\":{},\"elapsed_ms\":{:.3},\"status\":{}{}}}",
+        year, day, part, value, kind, elapsed.as_secs_f64() * 1000.0, string(&verdict.to_string()), error)
+}
+
+/// The parts of one day, a day without input gets an error record per part.
+fn day_records(result:&DayResult, answers:Option<&Answers>) -> Vec<String> {
+    match &result.parts {
+        Ok(parts) => (1..).zip(parts)
+            .map(|(part, PartResult { answer, elapsed })| record(result.year, result.day, part, answer, *elapsed, answers))
+            .collect(),
+        Err(message) => (1..=2)
+            .map(|part| format!("{{\"year\":{},\"day\":{},\"part\":{},\"answer\":null,\"type\":null,\"elapsed_ms\":0.000,\"status\":\"fail\",\"error\":{}}}",
+                result.year, result.day, part, string(message)))
+            .collect(),
+    }
+}
+
+/// A JSON array with one record per line, so that runs can be diffed.
+pub fn array(records:&[String]) -> String {
+    if records.is_empty() {
+        return "[]".to_string();
+    }
+    format!("[\n  {}\n]", records.join(",\n  "))
+}
+
+pub fn results(results:&[DayResult], answers:Option<&Answers>) -> String {
+    let records:Vec<String> = results.iter()
+        .flat_map(|result| day_records(result, answers))
+        .collect();
+    array(&records)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::Error;
+
+    #[test]
+    fn test_values() {
+        assert_eq!("\"a \\\"b\\\"\\n\\\\\"", string("a \"b\"\n\\"));
+        assert_eq!("42", answer(&Answer::Int(42)));
+        assert_eq!("\"170141183460469231731687303715884105727\"", answer(&Answer::BigInt(i128::MAX)));
+        assert_eq!("9007199254740991", answer(&Answer::Int(9007199254740991)));
+        assert_eq!("\"9007199254740992\"", answer(&Answer::Int(9007199254740992)));
+        assert_eq!("\"-9007199254740992\"", answer(&Answer::Int(-9007199254740992)));
+        assert_eq!("[1,\"9223372036854775807\"]", answer(&Answer::List(vec![1, i64::MAX])));
+        assert_eq!("[1,3,7]", answer(&Answer::List(vec![1, 3, 7])));
+        assert_eq!("[31,22]", answer(&Answer::Coord(31, 22)));
+        assert_eq!("null", answer(&Answer::Unsolved));
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::default();
+        answers.insert(18, 2, &Answer::Coord(31, 22));
+        let elapsed = Duration::from_micros(1500);
+
+        assert_eq!("{\"year\":2024,\"day\":18,\"part\":2,\"answer\":[31,22],\"type\":\"coord\",\"elapsed_ms\":1.500,\"status\":\"pass\"}",
+            record(2024, 18, 2, &Ok(Answer::Coord(31, 22)), elapsed, Some(&answers)));
+        assert_eq!("{\"year\":2024,\"day\":18,\"part\":1,\"answer\":294,\"type\":\"int\",\"elapsed_ms\":1.500,\"status\":\"unknown\"}",
+            record(2024, 18, 1, &Ok(Answer::Int(294)), elapsed, Some(&answers)));
+        assert_eq!("{\"year\":2024,\"day\":18,\"part\":2,\"answer\":null,\"type\":null,\"elapsed_ms\":1.500,\"status\":\"unknown\",\"error\":\"day 18: no path\"}",
+            record(2024, 18, 2, &Err(Error::solve("no path").with_day(18)), elapsed, None));
+
+        assert_eq!("[]", array(&[]));
+        assert_eq!("[\n  1,\n  2\n]", array(&["1".to_string(), "2".to_string()]));
+    }
+}
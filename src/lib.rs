@@ -0,0 +1,16 @@
+//! Advent of Code 2024 solvers. Every day implements `Solver` and is listed in the registry,
+//! the day modules (`day_01` .. `day_25`) also expose their helpers and data types.
+
+extern crate core;
+extern crate regex;
+extern crate permute;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod json;
+pub mod runner;
+pub mod scaffold;
+mod util;
+
+pub use util::*;
@@ -1,37 +1,575 @@
-extern crate core;
-extern crate regex;
-extern crate permute;
-extern crate proc_macro;
-
-use std::io::prelude::*;
+use std::io::{prelude::*, IsTerminal};
 use std::env;
+use std::time::{Duration, SystemTime};
 
-mod util;
+use advent_of_code_2024::{answers, bench, client, json, runner, scaffold};
+use advent_of_code_2024::{events, find_event, find_solver, latest_year, Params, Part, Puzzle};
+use advent_of_code_2024::log::{self, Level};
 
-use util::*;
+/// How results are printed: a table or plain answers for people, or JSON for scripts.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
 
-fn main() {
+/// The subcommands, any other first argument is a day or a range of days.
+const COMMANDS:&[&str] = &["list", "bench", "verify", "examples", "fetch", "submit", "new"];
 
-    let args: Vec<String> = env::args().collect();
+fn usage() -> ! {
+    println!("program [year] <day> [part] [--input <path>|--example <n>] [--param <name>=<value>]... [--format text|json]");
+    println!("program [year] all [--format text|json]");
+    println!("program [year] <first>..<last> [--format text|json]");
+    println!("program bench [year] <day> [part] [--runs <n>]");
+    println!("program verify [year] [all|<day>|<first>..<last>] [--record]");
+    println!("program examples [year] [all|<day>|<first>..<last>]");
+    println!("program fetch [year] [all|<day>|<first>..<last>]");
+    println!("program submit [year] <day> <part>");
+    println!("program new [year] <day> [--title <title>] [--force]");
+    println!("program list [year]");
+    println!();
+    println!("The year defaults to the latest event with solvers.");
+    println!("Logging goes to stderr: -v, -vv and -vvv for more detail, --quiet for errors only,");
+    println!("--log <day>[,<day>] to only log some days.");
+    println!("--timeout <seconds> stops a solver that runs longer and reports how far it got.");
+    println!("--jobs <n> runs days, or the work inside a single day, on n threads.");
+    println!("--param <name>=<value> sets a puzzle constant of a single day, `program list` shows them.");
+    std::process::exit(1);
+}
 
-    if args.len() != 3 {
-        println!("program <day> <part>");
-        std::process::exit(1);
+fn parse_solver(year:u16, arg:&str) -> &'static dyn Puzzle {
+    let day = match arg.parse::<u8>() {
+        Ok(day) => day,
+        Err(_) => {
+            eprintln!("Illegal day argument: {}", arg);
+            std::process::exit(1);
+        }
+    };
+    match find_solver(year, day) {
+        Some(solver) => solver,
+        None => {
+            eprintln!("No solver registered for day {} of {}, run `program list` to see the available days", day, year);
+            std::process::exit(1);
+        }
     }
+}
 
-    // Read arguments
-    let day = args[1].parse::<u8>().unwrap();
-    let part = match args[2].parse::<u8>() {
+fn parse_part(arg:&str) -> Part {
+    match arg.parse::<u8>() {
         Ok(1) => Part::Part1,
         Ok(2) => Part::Part2,
-        _ => panic!("illegal part arguments!")
-    };
+        _ => {
+            eprintln!("Illegal part argument: {}, expected 1 or 2", arg);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Removes `name <value>` from `args` and returns the value.
+fn take_option(args:&mut Vec<String>, name:&str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == name)?;
+    if index + 1 >= args.len() {
+        eprintln!("Missing value for {}", name);
+        std::process::exit(1);
+    }
+    args.remove(index);
+    Some(args.remove(index))
+}
+
+/// Removes the year from `args` when the argument at `index` is one, days never go beyond 25.
+fn take_year(args:&mut Vec<String>, index:usize) -> Option<u16> {
+    let year = args.get(index)?.parse::<u16>().ok().filter(|year| *year > 25)?;
+    args.remove(index);
+    Some(year)
+}
+
+/// Takes `-v`, `-vv`, `-vvv`, `--quiet` and `--log <days>` out of the arguments and sets up logging.
+fn configure_logging(args:&mut Vec<String>) {
+    if let Some(days) = take_option(args, "--log") {
+        // `3` and `day_03` both name the logging target of day 3
+        let targets = days.split(',')
+            .map(|day| match day.trim().parse::<u8>() {
+                Ok(day) => format!("day_{:02}", day),
+                Err(_) => day.trim().to_string(),
+            })
+            .collect();
+        log::set_targets(targets);
+    }
+
+    let mut verbosity = 0;
+    let mut quiet = false;
+    args.retain(|arg| {
+        if arg == "--quiet" || arg == "-q" {
+            quiet = true;
+            false
+        } else if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|ch| ch == 'v') {
+            verbosity += arg.len() as u8 - 1;
+            false
+        } else {
+            true
+        }
+    });
+
+    log::set_level(if quiet { Level::Error } else { Level::from_verbosity(verbosity) });
+}
+
+/// Takes `--timeout <seconds>` out of the arguments.
+fn take_timeout(args:&mut Vec<String>) -> Option<Duration> {
+    let seconds = take_option(args, "--timeout")?;
+    match seconds.parse::<f64>().ok().and_then(|seconds| Duration::try_from_secs_f64(seconds).ok()) {
+        Some(timeout) => Some(timeout),
+        None => {
+            eprintln!("Illegal --timeout argument: {}, expected a number of seconds", seconds);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Takes `--jobs <n>` out of the arguments, one thread when it is missing.
+fn take_jobs(args:&mut Vec<String>) -> usize {
+    match take_option(args, "--jobs").map(|value| value.parse::<usize>()) {
+        None => 1,
+        Some(Ok(jobs)) if jobs > 0 => jobs,
+        Some(_) => {
+            eprintln!("Illegal --jobs argument, expected a positive number");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Takes every `--param <name>=<value>` out of the arguments.
+fn take_params(args:&mut Vec<String>) -> Vec<String> {
+    let mut params = vec![];
+    while let Some(param) = take_option(args, "--param") {
+        params.push(param);
+    }
+    params
+}
+
+/// Takes `--format text|json` out of the arguments.
+fn take_format(args:&mut Vec<String>) -> Format {
+    match take_option(args, "--format").as_deref() {
+        None | Some("text") => Format::Text,
+        Some("json") => Format::Json,
+        Some(format) => {
+            eprintln!("Illegal --format argument: {}, expected text or json", format);
+            std::process::exit(1);
+        }
+    }
+}
 
-    // Read input
+fn load_answers(year:u16) -> answers::Answers {
+    answers::Answers::load(&answers::answers_path(year)).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    })
+}
+
+fn read_file(path:&str) -> String {
+    runner::read_input(path).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    })
+}
+
+/// Returns the piped input, or `None` when stdin is a terminal or empty.
+fn read_stdin() -> Option<String> {
+    if std::io::stdin().is_terminal() {
+        return None;
+    }
     let mut input = String::new();
     std::io::stdin().read_to_string(&mut input).expect("Failed to fetch input...");
+    Some(input).filter(|input| !input.is_empty())
+}
+
+fn run_bench(year:u16, args:&[String]) {
+    let mut args = args.to_vec();
+    let runs = match take_option(&mut args, "--runs").map(|value| value.parse::<usize>()) {
+        None => bench::DEFAULT_RUNS,
+        Some(Ok(runs)) if runs > 0 => runs,
+        Some(_) => {
+            eprintln!("Illegal --runs argument, expected a positive number");
+            std::process::exit(1);
+        }
+    };
+    let positional:Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+
+    let (solver, parts) = match positional[..] {
+        [day] => (parse_solver(year, day), vec![Part::Part1, Part::Part2]),
+        [day, part] => (parse_solver(year, day), vec![parse_part(part)]),
+        _ => usage(),
+    };
+
+    if let Err(message) = bench::bench(year, solver, &parts, runs) {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    }
+}
+
+fn confirm(question:&str) -> bool {
+    print!("{} [y/N] ", question);
+    std::io::stdout().flush().expect("Failed to write output...");
+    let mut reply = String::new();
+    std::io::stdin().read_line(&mut reply).expect("Failed to fetch input...");
+    reply.trim().eq_ignore_ascii_case("y")
+}
+
+fn run_verify(year:u16, args:&[String], options:&runner::RunOptions) {
+    let record = args.iter().any(|arg| arg == "--record");
+    let days = match args.iter().filter(|arg| *arg != "--record").collect::<Vec<_>>()[..] {
+        [] => runner::parse_days(year, "all").unwrap_or_default(),
+        [arg] => runner::parse_days(year, arg).unwrap_or_else(|| vec![parse_solver(year, arg).day()]),
+        _ => usage(),
+    };
+
+    let mut answers = load_answers(year);
+
+    let (unknown, failed) = runner::print_verify(&runner::run_days(year, &days, options), &answers);
 
-    let result = get_solution(day, part, input);
+    // Without --record only ask when somebody is there to answer
+    let interactive = std::io::stdin().is_terminal();
+    let mut changed = false;
+    for (day, part, answer) in unknown {
+        if record || (interactive && confirm(&format!("Record {} as the answer for day {} part {}?", answer, day, part))) {
+            answers.insert(day, part, &answer);
+            changed = true;
+        }
+    }
+    if changed {
+        let path = answers::answers_path(year);
+        if let Err(error) = answers.save(&path) {
+            eprintln!("error: failed to write {}: {}", path, error);
+            std::process::exit(1);
+        }
+        println!("Updated {}", path);
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn run_examples(year:u16, args:&[String], options:&runner::RunOptions) {
+    let days = match args {
+        [] => runner::parse_days(year, "all").unwrap_or_default(),
+        [arg] => runner::parse_days(year, arg).unwrap_or_else(|| vec![parse_solver(year, arg).day()]),
+        _ => usage(),
+    };
+
+    let results = runner::run_examples(year, &days, options).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    });
+    if runner::print_examples(&results) {
+        std::process::exit(1);
+    }
+}
+
+fn run_fetch(year:u16, args:&[String]) {
+    let days = match args {
+        [arg] => runner::parse_days(year, arg).unwrap_or_else(|| vec![parse_solver(year, arg).day()]),
+        _ => usage(),
+    };
+    let config = client::Config::load(client::CONFIG_PATH).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    });
+
+    for day in days {
+        let path = runner::input_path(year, day);
+        match client::fetch_input(&client::Curl, &config, year, day, &path) {
+            Ok(client::Fetched::Cached) => println!("{} is cached", path),
+            Ok(client::Fetched::Downloaded) => println!("Downloaded {}", path),
+            Err(message) => {
+                eprintln!("error: {}", message);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn run_submit(year:u16, args:&[String], options:&runner::RunOptions) {
+    let (solver, part) = match args {
+        [day, part] => (parse_solver(year, day), parse_part(part)),
+        _ => usage(),
+    };
+    let (day, level) = (solver.day(), part.number());
+
+    let input = read_file(&runner::input_path(year, day));
+    let answer = runner::solve_part(solver, &input, part, options).unwrap_or_else(|error| {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    });
+    if !answer.is_solved() {
+        eprintln!("Day {} has no solution for this part yet", day);
+        std::process::exit(2);
+    }
+
+    // Don't spend a submission on an answer whose verdict is known
+    let mut answers = load_answers(year);
+    match answers.known(day, level, &answer) {
+        Some(answers::Known::Correct) => {
+            println!("{} is the confirmed answer for day {} part {}", answer, day, level);
+            return;
+        },
+        Some(answers::Known::Solved { expected }) => {
+            println!("{} is wrong, the confirmed answer for day {} part {} is {}", answer, day, level, expected);
+            std::process::exit(1);
+        },
+        Some(answers::Known::Wrong { hint }) => {
+            let hint = hint.map(|hint| format!(", it is {}", hint)).unwrap_or_default();
+            println!("{} is wrong according to {}{}", answer, answers::answers_path(year), hint);
+            std::process::exit(1);
+        },
+        None => {},
+    }
+
+    // The site throttles per account, so the cooldown holds for every day and part
+    let left = client::cooldown_left(client::COOLDOWN_PATH, SystemTime::now()).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    });
+    if let Some(left) = left {
+        println!("Submitted too recently, try again in {}s", left.as_secs());
+        std::process::exit(1);
+    }
+
+    let config = client::Config::load(client::CONFIG_PATH).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    });
+    let outcome = client::submit_answer(&client::Curl, &config, year, day, level, &answer.to_string()).unwrap_or_else(|message| {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    });
+
+    let correct = match outcome {
+        client::Outcome::Correct => {
+            println!("{} is correct", answer);
+            answers.insert(day, level, &answer);
+            true
+        },
+        client::Outcome::Wrong { hint } => {
+            match hint {
+                Some(hint) => println!("{} is wrong, it is {}", answer, hint),
+                None => println!("{} is wrong", answer),
+            }
+            answers.insert_wrong(day, level, &answer, hint);
+            // The site makes you wait a minute after a wrong answer, without saying so
+            if let Err(message) = client::start_cooldown(client::COOLDOWN_PATH, SystemTime::now(), client::DEFAULT_WAIT) {
+                eprintln!("error: {}", message);
+            }
+            false
+        },
+        client::Outcome::Throttled { wait } => {
+            let wait = wait.unwrap_or(client::DEFAULT_WAIT);
+            println!("Submitted too recently, try again in {}s", wait.as_secs());
+            if let Err(message) = client::start_cooldown(client::COOLDOWN_PATH, SystemTime::now(), wait) {
+                eprintln!("error: {}", message);
+            }
+            std::process::exit(1);
+        },
+        client::Outcome::AlreadySolved => {
+            println!("Day {} part {} is already solved, or part 1 isn't yet", day, level);
+            std::process::exit(1);
+        },
+    };
+
+    let path = answers::answers_path(year);
+    if let Err(error) = answers.save(&path) {
+        eprintln!("error: failed to write {}: {}", path, error);
+        std::process::exit(1);
+    }
+    if !correct {
+        std::process::exit(1);
+    }
+}
+
+fn run_new(year:u16, args:&[String]) {
+    let mut args = args.to_vec();
+    let title = take_option(&mut args, "--title");
+    let force = args.iter().any(|arg| arg == "--force");
+    args.retain(|arg| arg != "--force");
+
+    let day = match &args[..] {
+        [day] => day,
+        _ => usage(),
+    };
+    let day = match day.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => day,
+        _ => {
+            eprintln!("Illegal day argument: {}, expected 1 to 25", day);
+            std::process::exit(1);
+        }
+    };
+
+    let title = title.unwrap_or_else(|| format!("Day {}", day));
+    match scaffold::scaffold(std::path::Path::new("."), year, day, &title, force) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path);
+            }
+        },
+        Err(message) => {
+            eprintln!("error: {}", message);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn main() {
+
+    let mut args: Vec<String> = env::args().collect();
+    configure_logging(&mut args);
+    let mut options = runner::RunOptions { timeout: take_timeout(&mut args), jobs: take_jobs(&mut args), ..Default::default() };
+    let params = take_params(&mut args);
+
+    // The year comes right after the command, or first when there is none
+    let command = args.get(1).map(|arg| arg.as_str()).filter(|arg| COMMANDS.contains(arg)).map(str::to_string);
+    let explicit_year = take_year(&mut args, if command.is_some() { 2 } else { 1 });
+    let year = explicit_year.unwrap_or_else(latest_year);
+    // Only `new` can start an event without solvers
+    if command.as_deref() != Some("new") && find_event(year).is_none() {
+        eprintln!("No solvers registered for {}, run `program list` to see the available years", year);
+        std::process::exit(1);
+    }
+
+    if args.len() == 2 && command.as_deref() == Some("list") {
+        for event in events().iter().filter(|event| explicit_year.is_none_or(|year| event.year == year)) {
+            for solver in event.solvers {
+                println!("{} {:>2} {}", event.year, solver.day(), solver.title());
+                for param in solver.params() {
+                    println!("          --param {}=<{}..{}>  {}", param.name, param.min, param.max, param.help);
+                }
+            }
+        }
+        return;
+    }
+
+    // Parameter names belong to one solver
+    let single_day = command.is_none() && args.len() > 1 && runner::parse_days(year, &args[1]).is_none();
+    if !params.is_empty() && !single_day {
+        eprintln!("--param only applies to a single day");
+        std::process::exit(1);
+    }
+
+    match command.as_deref() {
+        Some("bench") => return run_bench(year, &args[2..]),
+        Some("verify") => return run_verify(year, &args[2..], &options.with_default_timeout()),
+        Some("fetch") => return run_fetch(year, &args[2..]),
+        Some("submit") => return run_submit(year, &args[2..], &options),
+        Some("new") => return run_new(year, &args[2..]),
+        Some("examples") => return run_examples(year, &args[2..], &options.with_default_timeout()),
+        _ => {},
+    }
+
+    let format = take_format(&mut args);
+    if args.len() == 2 {
+        if let Some(days) = runner::parse_days(year, &args[1]) {
+            let results = runner::run_days(year, &days, &options.with_default_timeout());
+            let answers = load_answers(year);
+            match format {
+                Format::Text => runner::print_table(&results),
+                Format::Json => println!("{}", json::results(&results, Some(&answers))),
+            }
+            if runner::failed(&results, &answers) {
+                std::process::exit(1);
+            }
+            return;
+        }
+    }
+
+    // Read arguments
+    let input_arg = take_option(&mut args, "--input");
+    let example_arg = take_option(&mut args, "--example");
+    if args.len() != 2 && args.len() != 3 {
+        usage();
+    }
+    let solver = parse_solver(year, &args[1]);
+    let part = args.get(2).map(|arg| parse_part(arg));
+
+    // Read input: an explicit file, else piped stdin, else the stored input file
+    let example = example_arg.map(|example| example.parse::<u32>().unwrap_or_else(|_| {
+        eprintln!("Illegal --example argument: {}, expected a number", example);
+        std::process::exit(1);
+    }));
+    let path = match (input_arg, example) {
+        (Some(_), Some(_)) => {
+            eprintln!("--input and --example cannot be combined");
+            std::process::exit(1);
+        },
+        (Some(path), None) => Some(path),
+        (None, Some(example)) => Some(runner::example_path(year, solver.day(), example)),
+        (None, None) => None,
+    };
+    let example_params = match example {
+        Some(example) => runner::example_params(year, solver, example),
+        None => Ok(Default::default()),
+    };
+    options.params = example_params
+        .and_then(|example_params| Ok(example_params.merge(Params::parse(solver.params(), &params)?)))
+        .unwrap_or_else(|message| {
+            eprintln!("error: day {}: {}", solver.day(), message);
+            std::process::exit(1);
+        });
+    let (input, stored) = match path {
+        Some(path) => (read_file(&path), false),
+        None => match read_stdin() {
+            Some(input) => (input, false),
+            None => (read_file(&runner::input_path(year, solver.day())), true),
+        },
+    };
+
+    if format == Format::Json {
+        // Only the stored input and the examples have known answers to check against, for their own parameters
+        let answers = match example {
+            _ if !params.is_empty() => None,
+            Some(example) => Some(answers::Answers::load(&runner::example_answers_path(year, solver.day(), example)).unwrap_or_else(|message| {
+                eprintln!("error: {}", message);
+                std::process::exit(1);
+            })),
+            None => stored.then(|| load_answers(year)),
+        };
+        let parts = part.map_or(vec![Part::Part1, Part::Part2], |part| vec![part]);
+        let results = runner::run_parts(solver, &input, &parts, &options);
+        let records:Vec<String> = parts.iter().zip(&results)
+            .map(|(part, result)| json::record(year, solver.day(), part.number(), &result.answer, result.elapsed, answers.as_ref()))
+            .collect();
+        match part {
+            Some(_) => println!("{}", records[0]),
+            None => println!("{}", json::array(&records)),
+        }
+        if results.iter().any(|result| result.answer.is_err()) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let part = match part {
+        Some(part) => part,
+        None => {
+            let (answer1, answer2) = runner::solve_both(solver, &input, &options).unwrap_or_else(|error| {
+                eprintln!("error: {}", error);
+                std::process::exit(1);
+            });
+            println!("Part 1: {}", answer1);
+            println!("Part 2: {}", answer2);
+            return;
+        }
+    };
+
+    let result = match runner::solve_part(solver, &input, part, &options) {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(1);
+        }
+    };
+
+    if !result.is_solved() {
+        eprintln!("Day {} has no solution for this part yet", solver.day());
+        std::process::exit(2);
+    }
 
     println!("{}",result);
 
@@ -0,0 +1,448 @@
+use std::{fs, path::Path, sync::mpsc, thread, time::{Duration, Instant}};
+
+use crate::answers::{Answers, Known, Verdict};
+use crate::util::{find_event, find_solver, parallel_map, Answer, CancelToken, Context, Error, Params, Part, Puzzle, Result};
+
+
+/// How long a timed out solver gets to notice the cancellation and report its progress.
+const CANCEL_GRACE:Duration = Duration::from_millis(100);
+/// The timeout of each part in `all`, ranges, `verify` and `examples` without `--timeout`, so that one stuck day
+/// doesn't hang the whole run.
+pub const DEFAULT_TIMEOUT:Duration = Duration::from_secs(60);
+
+
+/// How solvers are run: `timeout` stops each part, `jobs` is the number of worker threads and `params` are the
+/// puzzle parameters of the solver.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub timeout:Option<Duration>,
+    pub jobs:usize,
+    pub params:Params,
+}
+
+impl RunOptions {
+    /// These options with `DEFAULT_TIMEOUT` when no timeout is set, for runs over several days.
+    pub fn with_default_timeout(&self) -> RunOptions {
+        RunOptions { timeout: self.timeout.or(Some(DEFAULT_TIMEOUT)), ..self.clone() }
+    }
+
+    /// The context a solver runs with, before any timeout.
+    pub fn context(&self) -> Context {
+        Context::default().with_jobs(self.jobs).with_params(self.params.clone())
+    }
+}
+
+impl Default for RunOptions {
+    fn default() -> RunOptions {
+        RunOptions { timeout: None, jobs: 1, params: Params::default() }
+    }
+}
+
+
+pub struct PartResult {
+    pub answer:Result<Answer>,
+    pub elapsed:Duration,
+}
+
+pub struct ExampleResult {
+    pub year:u16,
+    pub day:u8,
+    pub title:&'static str,
+    pub example:u32,
+    pub part:u8,
+    pub answer:Result<Answer>,
+    pub verdict:Verdict,
+}
+
+pub struct DayResult {
+    pub year:u16,
+    pub day:u8,
+    pub title:&'static str,
+    /// Either both parts, or the reason why the input could not be read.
+    pub parts:std::result::Result<Vec<PartResult>, String>,
+}
+
+
+pub fn input_path(year:u16, day:u8) -> String {
+    format!("input/{}/input_{:02}.txt", year, day)
+}
+
+pub fn example_path(year:u16, day:u8, example:u32) -> String {
+    format!("input/{}/examples/{:02}_{}.txt", year, day, example)
+}
+
+/// Expected answers of an example, in the format of `input/<year>/answers.txt`.
+pub fn example_answers_path(year:u16, day:u8, example:u32) -> String {
+    format!("input/{}/examples/{:02}_{}.answers", year, day, example)
+}
+
+/// Puzzle parameters of an example, one `name=value` per line. Most examples have none.
+pub fn example_params_path(year:u16, day:u8, example:u32) -> String {
+    format!("input/{}/examples/{:02}_{}.params", year, day, example)
+}
+
+/// Reads the parameters of an example for `solver`, an example without a params file has none.
+pub fn example_params(year:u16, solver:&dyn Puzzle, example:u32) -> std::result::Result<Params, String> {
+    let path = example_params_path(year, solver.day(), example);
+    if !Path::new(&path).exists() {
+        return Ok(Params::default());
+    }
+    let text = read_input(&path)?;
+    let assignments:Vec<&str> = text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    Params::parse(solver.params(), &assignments).map_err(|e| format!("{}: {}", path, e))
+}
+
+/// The example numbers of `day`, counting up from 1 until a file is missing.
+pub fn examples(year:u16, day:u8) -> Vec<u32> {
+    (1..).take_while(|example| Path::new(&example_path(year, day, *example)).exists()).collect()
+}
+
+/// Reads an input file, the error names the file.
+pub fn read_input(path:&str) -> std::result::Result<String, String> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => format!("input file {} not found", path),
+        _ => format!("failed to read {}: {}", path, e),
+    })
+}
+
+/// Parses `all`, `5..12` or `5..=12` into the days of `year` it covers. Both ends are inclusive.
+pub fn parse_days(year:u16, arg:&str) -> Option<Vec<u8>> {
+    let (first, last) = if arg == "all" {
+        (1, 25)
+    } else {
+        let (first, last) = arg.split_once("..")?;
+        let last = last.strip_prefix('=').unwrap_or(last);
+        (first.parse::<u8>().ok()?, last.parse::<u8>().ok()?)
+    };
+
+    Some(find_event(year)?.solvers.iter()
+        .map(|solver| solver.day())
+        .filter(|day| (first..=last).contains(day))
+        .collect())
+}
+
+/// Runs `run` with `ctx`, which is cancelled after `timeout`. The solver runs on its own thread, so one that never
+/// checks the token is abandoned instead of blocking the caller. `what` names the work in that error.
+fn with_timeout<T, F>(timeout:Option<Duration>, what:&str, ctx:Context, run:F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&Context) -> Result<T> + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return run(&ctx);
+    };
+
+    let ctx = Context { cancel: CancelToken::with_timeout(timeout), ..ctx };
+    let cancel = ctx.cancel.clone();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone if the solver was abandoned
+        let _ = sender.send(run(&ctx));
+    });
+
+    match receiver.recv_timeout(timeout + CANCEL_GRACE) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            cancel.cancel();
+            Err(Error::cancelled(&format!("{} after the {:?} timeout, the solver did not stop", what, timeout)))
+        },
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(Error::solve("the solver panicked")),
+    }
+}
+
+pub fn solve_part(solver:&'static dyn Puzzle, input:&str, part:Part, options:&RunOptions) -> Result<Answer> {
+    if options.timeout.is_none() {
+        return solver.solve_with(input, part, &options.context());
+    }
+    let input = input.to_string();
+    let what = format!("part {}", part.number());
+    with_timeout(options.timeout, &what, options.context(), move |ctx| solver.solve_with(&input, part, ctx))
+        .map_err(|e| e.with_day(solver.day()))
+}
+
+pub fn solve_both(solver:&'static dyn Puzzle, input:&str, options:&RunOptions) -> Result<(Answer, Answer)> {
+    if options.timeout.is_none() {
+        return solver.solve_both_with(input, &options.context());
+    }
+    let input = input.to_string();
+    with_timeout(options.timeout, "both parts", options.context(), move |ctx| solver.solve_both_with(&input, ctx))
+        .map_err(|e| e.with_day(solver.day()))
+}
+
+/// Solves and times the given parts one after the other.
+pub fn run_parts(solver:&'static dyn Puzzle, input:&str, parts:&[Part], options:&RunOptions) -> Vec<PartResult> {
+    parts.iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = solve_part(solver, input, *part, options);
+            PartResult { answer, elapsed: start.elapsed() }
+        })
+        .collect()
+}
+
+fn run_day(year:u16, solver:&'static dyn Puzzle, options:&RunOptions) -> DayResult {
+    let path = input_path(year, solver.day());
+    let parts = read_input(&path)
+        .map(|input| run_parts(solver, &input, &[Part::Part1, Part::Part2], options));
+
+    DayResult { year, day: solver.day(), title: solver.title(), parts }
+}
+
+/// Runs both parts of every registered day in `days`, each part is stopped after `timeout`. Several days are
+/// spread over `jobs` threads and then solve on a single thread each, a single day hands all jobs to its solver.
+/// The results are in the order of `days` either way.
+pub fn run_days(year:u16, days:&[u8], options:&RunOptions) -> Vec<DayResult> {
+    let solvers:Vec<&'static dyn Puzzle> = days.iter()
+        .filter_map(|day| find_solver(year, *day))
+        .collect();
+
+    let day_options = match solvers.len() {
+        1 => options.clone(),
+        _ => RunOptions { jobs: 1, ..options.clone() },
+    };
+    parallel_map(&solvers, options.jobs, |solver| run_day(year, *solver, &day_options))
+}
+
+
+/// Runs the examples of `days` against their expected answers. Parts without an expected answer are skipped,
+/// the examples of some days only cover one part.
+pub fn run_examples(year:u16, days:&[u8], options:&RunOptions) -> std::result::Result<Vec<ExampleResult>, String> {
+    let mut results = vec![];
+    for solver in days.iter().filter_map(|day| find_solver(year, *day)) {
+        let day = solver.day();
+        for example in examples(year, day) {
+            let answers = Answers::load(&example_answers_path(year, day, example))?;
+            let parts:Vec<Part> = [Part::Part1, Part::Part2].into_iter()
+                .filter(|part| answers.get(day, part.number()).is_some())
+                .collect();
+            if parts.is_empty() {
+                continue;
+            }
+
+            let input = read_input(&example_path(year, day, example))?;
+            let options = RunOptions { params: example_params(year, solver, example)?, ..options.clone() };
+            for (part, result) in parts.iter().zip(run_parts(solver, &input, &parts, &options)) {
+                let verdict = answers.check(day, part.number(), &result.answer);
+                results.push(ExampleResult { year, day, title: solver.title(), example, part: part.number(), answer: result.answer, verdict });
+            }
+        }
+    }
+    Ok(results)
+}
+
+
+pub fn format_duration(duration:Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1000.0 {
+        format!("{:.1} µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2} ms", micros / 1000.0)
+    } else {
+        format!("{:.2} s", micros / 1_000_000.0)
+    }
+}
+
+fn format_answer(answer:&Result<Answer>) -> String {
+    match answer {
+        Ok(answer) if answer.is_solved() => answer.to_string(),
+        Ok(_) => "-".to_string(),
+        Err(Error::Cancelled { .. }) => "cancelled".to_string(),
+        Err(_) => "error".to_string(),
+    }
+}
+
+pub fn print_table(results:&[DayResult]) {
+    println!("{:>3}  {:<24} {:>20} {:>10}  {:>20} {:>10}", "Day", "Title", "Part 1", "Time", "Part 2", "Time");
+
+    let mut total = Duration::ZERO;
+    let mut errors = vec![];
+    for result in results {
+        match &result.parts {
+            Ok(parts) => {
+                let mut row = format!("{:>3}  {:<24}", result.day, result.title);
+                for part in parts {
+                    row += &format!(" {:>20} {:>10} ", format_answer(&part.answer), format_duration(part.elapsed));
+                    total += part.elapsed;
+                    if let Err(error) = &part.answer {
+                        errors.push(error.to_string());
+                    }
+                }
+                println!("{}", row.trim_end());
+            },
+            Err(message) => {
+                println!("{:>3}  {:<24} {}", result.day, result.title, message);
+            },
+        }
+    }
+
+    println!("{:>3}  {:<24} {:>64}", "", "Total", format_duration(total));
+
+    for error in errors {
+        eprintln!("error: {}", error);
+    }
+}
+
+/// The answer for a verify line, with the expected answer when they differ.
+fn format_detail(verdict:&Verdict, answer:&Result<Answer>) -> String {
+    match (verdict, answer) {
+        (_, Err(error)) => error.to_string(),
+        (Verdict::Fail { expected }, Ok(answer)) => format!("{} (expected {})", answer, expected),
+        (_, Ok(_)) => format_answer(answer),
+    }
+}
+
+/// Prints a pass/fail line per example part, returns whether anything failed.
+pub fn print_examples(results:&[ExampleResult]) -> bool {
+    for result in results {
+        println!("{:>3}  {:<24} example {} part {} {:<7} {}",
+            result.day, result.title, result.example, result.part, result.verdict, format_detail(&result.verdict, &result.answer));
+    }
+    results.iter().any(|result| result.verdict != Verdict::Pass)
+}
+
+/// Prints a pass/fail/unknown line per part. Returns the solved parts without a stored answer, and whether anything failed.
+pub fn print_verify(results:&[DayResult], answers:&Answers) -> (Vec<(u8, u8, Answer)>, bool) {
+    let mut unknown = vec![];
+    let mut failed = false;
+    for result in results {
+        let parts = match &result.parts {
+            Ok(parts) => parts,
+            Err(message) => {
+                println!("{:>3}  {:<24} - {:<7} {}", result.day, result.title, "fail", message);
+                failed = true;
+                continue;
+            },
+        };
+
+        for (part, part_result) in (1..).zip(parts) {
+            let verdict = answers.check(result.day, part, &part_result.answer);
+            let detail = format_detail(&verdict, &part_result.answer);
+            println!("{:>3}  {:<24} {} {:<7} {}", result.day, result.title, part, verdict, detail);
+
+            match (verdict, &part_result.answer) {
+                (Verdict::Fail { .. }, _) => failed = true,
+                (Verdict::Unknown, Ok(answer)) if answer.is_solved() => unknown.push((result.day, part, answer.clone())),
+                _ => {},
+            }
+        }
+    }
+
+    (unknown, failed)
+}
+
+/// Whether any day couldn't be read or solved, or gave an answer that the stored answers rule out.
+pub fn failed(results:&[DayResult], answers:&Answers) -> bool {
+    results.iter().any(|result| match &result.parts {
+        Ok(parts) => (1..).zip(parts).any(|(part, part_result)| {
+            let wrong = match &part_result.answer {
+                Ok(answer) => matches!(answers.known(result.day, part, answer), Some(Known::Wrong { .. })),
+                Err(_) => true,
+            };
+            wrong || matches!(answers.check(result.day, part, &part_result.answer), Verdict::Fail { .. })
+        }),
+        Err(_) => true,
+    })
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(Some((1..=25).collect()), parse_days(2024, "all"));
+        assert_eq!(Some((5..=12).collect()), parse_days(2024, "5..12"));
+        assert_eq!(Some(vec![24, 25]), parse_days(2024, "24..=30"));
+        assert_eq!(Some(vec![]), parse_days(2024, "12..5"));
+        assert_eq!(None, parse_days(2024, "5"));
+        assert_eq!(None, parse_days(2024, "x..3"));
+        assert_eq!(None, parse_days(1999, "all"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("250.0 µs", format_duration(Duration::from_micros(250)));
+        assert_eq!("12.50 ms", format_duration(Duration::from_micros(12_500)));
+        assert_eq!("2.00 s", format_duration(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!("input/2024/input_07.txt", input_path(2024, 7));
+        assert_eq!("input/2024/examples/07_2.txt", example_path(2024, 7, 2));
+        assert_eq!("input/2024/examples/07_2.answers", example_answers_path(2024, 7, 2));
+        assert_eq!("input/2024/examples/07_2.params", example_params_path(2024, 7, 2));
+        assert_eq!(Err("input file input/2024/input_99.txt not found".to_string()), read_input(&input_path(2024, 99)));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let stuck = with_timeout(Some(Duration::from_millis(10)), "part 2", Context::default(), |_| loop { thread::sleep(Duration::from_millis(5)) });
+        assert_eq!(Err::<(), _>(Error::cancelled("part 2 after the 10ms timeout, the solver did not stop")), stuck);
+
+        let cooperative = with_timeout(Some(Duration::from_millis(10)), "part 2", Context::default(), |ctx| {
+            let mut step = 0;
+            loop {
+                ctx.cancel.check(|| format!("step {}", step))?;
+                step += 1;
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+        assert!(matches!(cooperative, Err::<(), _>(Error::Cancelled { progress, .. }) if progress.starts_with("step ")));
+
+        assert_eq!(Ok(7), with_timeout(None, "part 1", Context::default(), |_| Ok(7)));
+        assert_eq!(Ok(4), with_timeout(Some(Duration::from_secs(1)), "part 1", Context::default().with_jobs(4), |ctx| Ok(ctx.jobs)));
+    }
+
+    #[test]
+    fn test_run_days() {
+        let options = RunOptions { jobs: 4, ..RunOptions::default() };
+        let results = run_days(2024, &[3, 1, 2], &options);
+        let days:Vec<u8> = results.iter().map(|result| result.day).collect();
+        assert_eq!(vec![3, 1, 2], days);
+
+        let answers:Vec<Vec<String>> = results.iter()
+            .map(|result| result.parts.as_ref().unwrap().iter().map(|part| format_answer(&part.answer)).collect())
+            .collect();
+        let sequential:Vec<Vec<String>> = run_days(2024, &[3, 1, 2], &RunOptions::default()).iter()
+            .map(|result| result.parts.as_ref().unwrap().iter().map(|part| format_answer(&part.answer)).collect())
+            .collect();
+        assert_eq!(sequential, answers);
+    }
+
+    #[test]
+    fn test_failed() {
+        let day = |parts:std::result::Result<Vec<Result<Answer>>, String>| vec![DayResult {
+            year: 2024, day: 1, title: "Historian Hysteria",
+            parts: parts.map(|answers| answers.into_iter().map(|answer| PartResult { answer, elapsed: Duration::ZERO }).collect()),
+        }];
+        let answers = Answers::parse("1 1 11\n1 2 40 wrong too high").unwrap();
+        assert!(!failed(&day(Ok(vec![Ok(Answer::Int(11)), Ok(Answer::Int(31))])), &answers));
+        assert!(!failed(&day(Ok(vec![Ok(Answer::Int(11)), Ok(Answer::Unsolved)])), &answers));
+        assert!(failed(&day(Ok(vec![Ok(Answer::Int(12)), Ok(Answer::Int(31))])), &answers));
+        assert!(failed(&day(Ok(vec![Ok(Answer::Int(11)), Ok(Answer::Int(41))])), &answers));
+        assert!(failed(&day(Ok(vec![Ok(Answer::Int(11)), Err(Error::solve("no path"))])), &answers));
+        assert!(failed(&day(Err("input file input/2024/input_01.txt not found".to_string())), &answers));
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(vec![1, 2], examples(2024, 3));
+        assert_eq!(Vec::<u32>::new(), examples(2024, 23));
+
+        let days = parse_days(2024, "all").unwrap();
+        let results = run_examples(2024, &days, &RunOptions::default()).unwrap();
+        assert!(results.iter().any(|result| result.day == 17 && result.example == 3));
+        assert!(results.iter().any(|result| result.day == 18 && result.example == 1));
+        assert_eq!(Some(12), example_params(2024, find_solver(2024, 18).unwrap(), 1).unwrap().get::<u64>("bytes"));
+        assert!(example_params(2024, find_solver(2024, 3).unwrap(), 1).unwrap().is_empty());
+        for result in results {
+            assert_eq!(Verdict::Pass, result.verdict, "day {} example {} part {}", result.day, result.example, result.part);
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use std::{fs, path::Path};
+
+use crate::runner::{example_answers_path, example_path, input_path};
+
+
+/// The module a new day starts from. `__NN__` is the zero padded day, `__DAY__` the day, `__YEAR__` the year
+/// and `__TITLE__` the title.
+const TEMPLATE:&str = r#"use super::{Answer, Context, Result, Solver};
+
+
+pub struct Day__NN__;
+
+impl Solver for Day__NN__ {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        __DAY__
+    }
+
+    fn title(&self) -> &'static str {
+        "__TITLE__"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part2(input))
+    }
+}
+
+
+fn part1(_lines:&[&str]) -> Answer {
+    Answer::Unsolved
+}
+
+fn part2(_lines:&[&str]) -> Answer {
+    Answer::Unsolved
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+        let input = include_str!("../../../input/__YEAR__/examples/__NN___1.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day__NN__.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/__YEAR__/input___NN__.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day__NN__.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+        let input = include_str!("../../../input/__YEAR__/examples/__NN___1.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day__NN__.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/__YEAR__/input___NN__.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day__NN__.solve(input, Part2));
+    }
+}
+"#;
+
+/// The module of a new event, it declares the day modules and registers their solvers.
+const YEAR_TEMPLATE:&str = r#"//! Advent of Code __YEAR__.
+
+use super::{Answer, Context, Puzzle, Result, Solver};
+#[cfg(test)]
+use super::Part;
+
+
+pub static SOLVERS: &[&dyn Puzzle] = &[
+];
+"#;
+
+/// Declares the event modules and lists the events.
+pub const EVENTS_PATH:&str = "src/util.rs";
+
+
+pub fn module_path(year:u16, day:u8) -> String {
+    format!("src/util/y{}/day_{:02}.rs", year, day)
+}
+
+/// The module of an event, with the registry of its solvers.
+pub fn registry_path(year:u16) -> String {
+    format!("src/util/y{}.rs", year)
+}
+
+pub fn render(year:u16, day:u8, title:&str) -> String {
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    TEMPLATE
+        .replace("__NN__", &format!("{:02}", day))
+        .replace("__DAY__", &day.to_string())
+        .replace("__YEAR__", &year.to_string())
+        .replace("__TITLE__", &title)
+}
+
+/// Adds `pub mod day_NN;` and the solver of `day` to the registry of an event, both in day order.
+/// Returns `None` when the day is registered already.
+pub fn register_day(source:&str, day:u8) -> Result<Option<String>, String> {
+    let module = format!("pub mod day_{:02};", day);
+    let entry = format!("    &day_{:02}::Day{:02},", day, day);
+    register(source, &module, &entry, "pub mod day_", "pub static SOLVERS")
+}
+
+/// Adds `pub mod yYYYY;` and the event of `year` to the list of events, both in year order.
+/// Returns `None` when the year is registered already.
+pub fn register_year(source:&str, year:u16) -> Result<Option<String>, String> {
+    let module = format!("pub mod y{};", year);
+    let entry = format!("    Event {{ year: {}, solvers: y{}::SOLVERS }},", year, year);
+    register(source, &module, &entry, "pub mod y", "static EVENTS")
+}
+
+/// Inserts `module` among the declarations starting with `prefix`, or above the imports when there are none yet,
+/// and `entry` into the list that starts at the line starting with `list`.
+fn register(source:&str, module:&str, entry:&str, prefix:&str, list:&str) -> Result<Option<String>, String> {
+    if source.lines().any(|line| line == module) {
+        return Ok(None);
+    }
+
+    let mut lines:Vec<&str> = source.lines().collect();
+    if insert_sorted(&mut lines, module, |line| line.starts_with(prefix)).is_none() {
+        let index = lines.iter().position(|line| line.starts_with("use "))
+            .ok_or_else(|| format!("found no `{}` lines", prefix))?;
+        lines.splice(index..index, [module, ""]);
+    }
+
+    let start = lines.iter().position(|line| line.starts_with(list))
+        .ok_or_else(|| format!("found no `{}` list", list))?;
+    let end = start + lines[start..].iter().position(|line| *line == "];")
+        .ok_or("found no end of the list")?;
+    let mut registry = lines[start + 1..end].to_vec();
+    registry.push(entry);
+    registry.sort();
+    lines.splice(start + 1..end, registry);
+
+    let mut text = lines.join("\n");
+    if source.ends_with('\n') {
+        text.push('\n');
+    }
+    Ok(Some(text))
+}
+
+/// Inserts `line` among the consecutive `matches` lines, in sorted order.
+fn insert_sorted<'a, F: Fn(&str) -> bool>(lines:&mut Vec<&'a str>, line:&'a str, matches:F) -> Option<()> {
+    let first = lines.iter().position(|line| matches(line))?;
+    let count = lines[first..].iter().take_while(|line| matches(line)).count();
+    let index = first + lines[first..first + count].iter().take_while(|existing| **existing < line).count();
+    lines.insert(index, line);
+    Some(())
+}
+
+/// Writes `content` unless `path` exists, returns whether it was written.
+fn create(root:&Path, path:&str, content:&str) -> Result<bool, String> {
+    let path = root.join(path);
+    if path.exists() {
+        return Ok(false);
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    }
+    fs::write(&path, content).map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    Ok(true)
+}
+
+/// Rewrites the source file at `path` with `register`, returns whether it changed.
+fn update<F: Fn(&str) -> Result<Option<String>, String>>(root:&Path, path:&str, register:F) -> Result<bool, String> {
+    let file = root.join(path);
+    let source = fs::read_to_string(&file).map_err(|e| format!("failed to read {}: {}", path, e))?;
+    match register(&source).map_err(|e| format!("{}: {}", path, e))? {
+        Some(source) => {
+            fs::write(&file, source).map_err(|e| format!("failed to write {}: {}", path, e))?;
+            Ok(true)
+        },
+        None => Ok(false),
+    }
+}
+
+/// Creates the module of a new day under `root` and registers it, the first day of a year also creates and registers
+/// the module of its event. The puzzle input and the first example start out empty, next to an answers file with
+/// commented placeholder entries. Existing files are kept, except for the day module with `force`. Returns the files
+/// that were written.
+pub fn scaffold(root:&Path, year:u16, day:u8, title:&str, force:bool) -> Result<Vec<String>, String> {
+    let module = module_path(year, day);
+    if root.join(&module).exists() && !force {
+        return Err(format!("{} exists, use --force to overwrite it", module));
+    }
+
+    let mut written = vec![];
+    let registry = registry_path(year);
+    if create(root, &registry, &YEAR_TEMPLATE.replace("__YEAR__", &year.to_string()))? {
+        written.push(registry.clone());
+        if update(root, EVENTS_PATH, |source| register_year(source, year))? {
+            written.push(EVENTS_PATH.to_string());
+        }
+    }
+
+    if !create(root, &module, &render(year, day, title))? {
+        fs::write(root.join(&module), render(year, day, title)).map_err(|e| format!("failed to write {}: {}", module, e))?;
+    }
+    written.push(module);
+
+    if update(root, &registry, |source| register_day(source, day))? && !written.contains(&registry) {
+        written.push(registry);
+    }
+
+    let answers = format!("# day part answer\n# {} 1 <answer>\n# {} 2 <answer>\n", day, day);
+    let placeholders = [
+        (input_path(year, day), ""),
+        (example_path(year, day, 1), ""),
+        (example_answers_path(year, day, 1), answers.as_str()),
+    ];
+    for (path, content) in placeholders {
+        if create(root, &path, content)? {
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const EVENTS:&str = "pub mod y2024;
+mod answer;
+
+static EVENTS: &[Event] = &[
+    Event { year: 2024, solvers: y2024::SOLVERS },
+];
+";
+
+    const REGISTRY:&str = "pub mod day_01;
+pub mod day_03;
+
+use super::{Answer, Context, Puzzle, Result, Solver};
+
+
+pub static SOLVERS: &[&dyn Puzzle] = &[
+    &day_01::Day01,
+    &day_03::Day03,
+];
+";
+
+    #[test]
+    fn test_render() {
+        let module = render(2024, 7, "Bridge \"Repair\"");
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("        7\n"));
+        assert!(module.contains("\"Bridge \\\"Repair\\\"\""));
+        assert!(module.contains("include_str!(\"../../../input/2024/examples/07_1.txt\")"));
+        assert!(module.contains("include_str!(\"../../../input/2024/input_07.txt\")"));
+    }
+
+    #[test]
+    fn test_register() {
+        let source = register_day(REGISTRY, 2).unwrap().unwrap();
+        assert_eq!("pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+
+use super::{Answer, Context, Puzzle, Result, Solver};
+
+
+pub static SOLVERS: &[&dyn Puzzle] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+];
+", source);
+        assert_eq!(Ok(None), register_day(&source, 2));
+        assert!(register_day("mod solver;\n", 2).is_err());
+
+        let source = register_year(EVENTS, 2023).unwrap().unwrap();
+        assert_eq!("pub mod y2023;
+pub mod y2024;
+mod answer;
+
+static EVENTS: &[Event] = &[
+    Event { year: 2023, solvers: y2023::SOLVERS },
+    Event { year: 2024, solvers: y2024::SOLVERS },
+];
+", source);
+
+        let year = YEAR_TEMPLATE.replace("__YEAR__", "2025");
+        let source = register_day(&year, 1).unwrap().unwrap();
+        assert!(source.starts_with("//! Advent of Code 2025.\n\npub mod day_01;\n\nuse super::"));
+        assert!(source.contains("    &day_01::Day01,\n];"));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/util")).unwrap();
+        fs::write(root.join(EVENTS_PATH), EVENTS).unwrap();
+        fs::write(root.join(registry_path(2024)), REGISTRY).unwrap();
+
+        let written = scaffold(&root, 2024, 2, "Red-Nosed Reports", false).unwrap();
+        assert_eq!(vec!["src/util/y2024/day_02.rs", "src/util/y2024.rs", "input/2024/input_02.txt",
+            "input/2024/examples/02_1.txt", "input/2024/examples/02_1.answers"], written);
+        assert!(fs::read_to_string(root.join(registry_path(2024))).unwrap().contains("&day_02::Day02,"));
+
+        assert!(scaffold(&root, 2024, 2, "Red-Nosed Reports", false).is_err());
+        assert_eq!(vec!["src/util/y2024/day_02.rs"], scaffold(&root, 2024, 2, "Red-Nosed Reports", true).unwrap());
+
+        let written = scaffold(&root, 2025, 1, "Day 1", false).unwrap();
+        assert_eq!(vec!["src/util/y2025.rs", "src/util.rs", "src/util/y2025/day_01.rs", "input/2025/input_01.txt",
+            "input/2025/examples/01_1.txt", "input/2025/examples/01_1.answers"], written);
+        assert!(fs::read_to_string(root.join(EVENTS_PATH)).unwrap().contains("Event { year: 2025, solvers: y2025::SOLVERS },"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
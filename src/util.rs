@@ -1,28 +1,25 @@
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
+pub mod y2024;
+mod answer;
+mod context;
+mod error;
+mod geometry;
+mod grid;
+pub mod log;
+mod parallel;
+mod params;
+pub mod pattern;
+mod search;
+mod solver;
+
+pub use answer::Answer;
+pub use context::{CancelToken, Context};
+pub use error::{parse_number, Error, Result};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use parallel::parallel_map;
+pub use params::{Param, Params};
+pub use search::{astar, bfs, dijkstra, Search};
+pub use solver::{Puzzle, Solver};
 
 
 
@@ -32,41 +29,40 @@ pub enum Part {
     Part2,
 }
 
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        }
+    }
+}
 
 
-pub fn get_solution(day:u8, part:Part, input:String) -> String {
-    match day {
-        1 => day_01::solve(input, part),
-        2 => day_02::solve(input, part),
-        3 => day_03::solve(input, part),
-        4 => day_04::solve(input, part),
-        5 => day_05::solve(input, part),
-        6 => day_06::solve(input, part),
-        7 => day_07::solve(input, part),
-        8 => day_08::solve(input, part),
-        9 => day_09::solve(input, part),
-        10 => day_10::solve(input, part),
-        11 => day_11::solve(input, part),
-        12 => day_12::solve(input, part),
-        13 => day_13::solve(input, part),
-        14 => day_14::solve(input, part),
-        15 => day_15::solve(input, part),
-        16 => day_16::solve(input, part),
-        17 => day_17::solve(input, part),
-        18 => day_18::solve(input, part),
-        19 => day_19::solve(input, part),
-        20 => day_20::solve(input, part),
-        21 => day_21::solve(input, part),
-        22 => day_22::solve(input, part),
-        23 => day_23::solve(input, part),
-        24 => day_24::solve(input, part),
-        25 => day_25::solve(input, part),
+/// The solvers of one Advent of Code event.
+pub struct Event {
+    pub year:u16,
+    pub solvers:&'static [&'static dyn Puzzle],
+}
 
-        _ => panic!("..."),
-    }
+static EVENTS: &[Event] = &[
+    Event { year: 2024, solvers: y2024::SOLVERS },
+];
 
-}
 
+pub fn events() -> &'static [Event] {
+    EVENTS
+}
 
+pub fn find_event(year:u16) -> Option<&'static Event> {
+    EVENTS.iter().find(|event| event.year == year)
+}
 
+/// The most recent event, the default when no year is given.
+pub fn latest_year() -> u16 {
+    EVENTS.iter().map(|event| event.year).max().expect("at least one event is registered")
+}
 
+pub fn find_solver(year:u16, day:u8) -> Option<&'static dyn Puzzle> {
+    find_event(year)?.solvers.iter().copied().find(|solver| solver.day() == day)
+}
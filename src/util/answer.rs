@@ -0,0 +1,119 @@
+use std::fmt;
+
+
+/// The result of one puzzle part. `Unsolved` marks days that only return a placeholder so far.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+    List(Vec<i64>),
+    Coord(i64, i64),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        *self != Answer::Unsolved
+    }
+
+    /// The name of the variant, for machine readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::BigInt(_) => "bigint",
+            Answer::Text(_) => "text",
+            Answer::List(_) => "list",
+            Answer::Coord(_, _) => "coord",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::BigInt(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::List(values) => {
+                let items:Vec<String> = values.iter().map(|value| value.to_string()).collect();
+                write!(f, "{}", items.join(","))
+            },
+            Answer::Coord(x, y) => write!(f, "{},{}", x, y),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Int(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::BigInt(value as i128),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::from(value as u64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::BigInt(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("42", Answer::Int(42).to_string());
+        assert_eq!("1,3,7", Answer::List(vec![1,3,7]).to_string());
+        assert_eq!("31,22", Answer::Coord(31,22).to_string());
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::Int(11), Answer::from(11_u32));
+        assert_eq!(Answer::BigInt(u64::MAX as i128), Answer::from(u64::MAX));
+        assert!(!Answer::Unsolved.is_solved());
+        assert_eq!("coord", Answer::Coord(31,22).kind());
+    }
+}
@@ -0,0 +1,94 @@
+use std::{sync::{atomic::{AtomicBool, Ordering}, Arc}, time::{Duration, Instant}};
+
+use super::{Error, Params, Result};
+
+
+/// Cooperative cancellation. Long running loops call `check`, which fails once the token is
+/// cancelled or its deadline has passed. Clones share the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled:Arc<AtomicBool>,
+    deadline:Option<Instant>,
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn with_timeout(timeout:Duration) -> CancelToken {
+        CancelToken { cancelled: Arc::default(), deadline: Some(Instant::now() + timeout) }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Fails with `Error::Cancelled` when cancelled, `progress` describes how far the solver got.
+    pub fn check<F: FnOnce() -> String>(&self, progress:F) -> Result<()> {
+        if self.is_cancelled() {
+            Err(Error::cancelled(&progress()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+
+/// Everything a solver gets besides its input.
+#[derive(Debug, Clone)]
+pub struct Context {
+    pub cancel:CancelToken,
+    /// How many threads a solver may use for independent work items, at least 1.
+    pub jobs:usize,
+    /// The puzzle parameters set from the command line, see `Solver::params`.
+    pub params:Params,
+}
+
+impl Context {
+    pub fn new(cancel:CancelToken) -> Context {
+        Context { cancel, jobs: 1, params: Params::default() }
+    }
+
+    pub fn with_jobs(self, jobs:usize) -> Context {
+        Context { jobs: jobs.max(1), ..self }
+    }
+
+    pub fn with_params(self, params:Params) -> Context {
+        Context { params, ..self }
+    }
+}
+
+impl Default for Context {
+    fn default() -> Context {
+        Context::new(CancelToken::new())
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+        assert_eq!(Ok(()), clone.check(|| "nothing".to_string()));
+
+        token.cancel();
+        assert!(clone.is_cancelled());
+        assert_eq!(Err(Error::cancelled("step 3")), clone.check(|| "step 3".to_string()));
+    }
+
+    #[test]
+    fn test_timeout() {
+        assert!(CancelToken::with_timeout(Duration::ZERO).is_cancelled());
+        assert!(!CancelToken::with_timeout(Duration::from_secs(60)).is_cancelled());
+    }
+}
@@ -0,0 +1,97 @@
+use std::{fmt, str::FromStr};
+
+
+/// Everything that can go wrong while solving a day. Line and column numbers are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input could not be parsed, `text` is the offending part of the line.
+    Parse { day:u8, line:usize, column:usize, text:String, message:String },
+    /// The input was parsed, but the solver could not come up with an answer.
+    Solve { day:u8, message:String },
+    /// The solver was cancelled or timed out, `progress` tells how far it got and may be empty.
+    Cancelled { day:u8, progress:String },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+
+impl Error {
+    /// Error for `text`, which must be a slice of `line`. `line_index` is the 0-based index from `lines().enumerate()`.
+    pub fn at(line_index:usize, line:&str, text:&str, message:&str) -> Error {
+        let offset = (text.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = if offset <= line.len() {
+            line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        Error::Parse { day: 0, line: line_index + 1, column, text: text.to_string(), message: message.to_string() }
+    }
+
+    /// Error for input that stops before `line_index`.
+    pub fn end_of_input(line_index:usize, message:&str) -> Error {
+        Error::Parse { day: 0, line: line_index + 1, column: 1, text: String::new(), message: message.to_string() }
+    }
+
+    pub fn solve(message:&str) -> Error {
+        Error::Solve { day: 0, message: message.to_string() }
+    }
+
+    pub fn cancelled(progress:&str) -> Error {
+        Error::Cancelled { day: 0, progress: progress.to_string() }
+    }
+
+    /// Fills in the day, the parsers themselves do not know which day they belong to.
+    pub fn with_day(self, day:u8) -> Error {
+        match self {
+            Error::Parse { line, column, text, message, .. } => Error::Parse { day, line, column, text, message },
+            Error::Solve { message, .. } => Error::Solve { day, message },
+            Error::Cancelled { progress, .. } => Error::Cancelled { day, progress },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse { day, line, column, text, message } => {
+                if text.is_empty() {
+                    write!(f, "day {}, line {}, column {}: {}", day, line, column, message)
+                } else {
+                    write!(f, "day {}, line {}, column {}: {} (found '{}')", day, line, column, message, text)
+                }
+            },
+            Error::Solve { day, message } => write!(f, "day {}: {}", day, message),
+            Error::Cancelled { day, progress } => {
+                if progress.is_empty() {
+                    write!(f, "day {}: cancelled", day)
+                } else {
+                    write!(f, "day {}: cancelled at {}", day, progress)
+                }
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+
+/// Parses `token`, a slice of `line`, and reports where it is on failure.
+pub fn parse_number<T: FromStr>(line_index:usize, line:&str, token:&str) -> Result<T> {
+    token.parse::<T>().map_err(|_| Error::at(line_index, line, token, "expected a number"))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let line = "47|x3";
+        let error = parse_number::<u32>(2, line, &line[3..]).unwrap_err().with_day(5);
+        assert_eq!(Error::Parse { day: 5, line: 3, column: 4, text: "x3".to_string(), message: "expected a number".to_string() }, error);
+        assert_eq!("day 5, line 3, column 4: expected a number (found 'x3')", error.to_string());
+    }
+}
@@ -0,0 +1,228 @@
+use std::{fmt, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}};
+
+
+/// A position or a vector on a map, `x` grows to the right and `y` grows down. Signed, so that a step off the
+/// edge of a map is a position outside it instead of an underflow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x:i32,
+    pub y:i32,
+}
+
+impl Point {
+    pub const ORIGIN:Point = Point::new(0, 0);
+
+    pub const fn new(x:i32, y:i32) -> Point {
+        Point { x, y }
+    }
+
+    /// The neighbouring point in direction `dir`.
+    pub fn step(self, dir:Direction) -> Point {
+        self + dir.delta()
+    }
+
+    /// The 4 neighbours up, right, down and left.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ORTHOGONAL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The 8 neighbours, diagonals included, clockwise from up.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    pub fn manhattan(self, other:Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl From<(i32,i32)> for Point {
+    fn from((x, y):(i32,i32)) -> Point {
+        Point::new(x, y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other:Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, dir:Direction) -> Point {
+        self.step(dir)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other:Point) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, dir:Direction) {
+        *self = self.step(dir);
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other:Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other:Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor:i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+
+/// A compass direction on a map, up is towards the first line. The variants are in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The 4 directions along the axes, clockwise from up.
+    pub const ORTHOGONAL:[Direction;4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// All 8 directions, clockwise from up.
+    pub const ALL:[Direction;8] = [
+        Direction::Up, Direction::UpRight, Direction::Right, Direction::DownRight,
+        Direction::Down, Direction::DownLeft, Direction::Left, Direction::UpLeft,
+    ];
+
+    /// The direction of an arrow `^`, `v`, `<` or `>`.
+    pub fn from_arrow(ch:char) -> Option<Direction> {
+        match ch {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// One step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotated clockwise by `eighths` eighth turns, counterclockwise when negative.
+    pub fn rotate(self, eighths:i32) -> Direction {
+        Direction::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let point = Point::new(3, 4);
+        assert_eq!(Point::new(3, 3), point.step(Direction::Up));
+        assert_eq!(Point::new(2, 5), point + Direction::DownLeft);
+        assert_eq!(Point::new(5, 2), point + Point::new(2, -2));
+        assert_eq!(Point::new(1, 2), point - Point::new(2, 2));
+        assert_eq!(Point::new(-6, -8), -point * 2);
+        assert_eq!(7, point.manhattan(Point::ORIGIN));
+        assert_eq!(Point::new(3, 4), Point::from((3, 4)));
+        assert_eq!("3,4", point.to_string());
+
+        let mut moved = point;
+        moved += Direction::Right;
+        moved -= Point::new(0, 1);
+        assert_eq!(Point::new(4, 3), moved);
+
+        assert_eq!(vec![Point::new(0, -1), Point::new(1, 0), Point::new(0, 1), Point::new(-1, 0)], Point::ORIGIN.neighbours4().collect::<Vec<_>>());
+        assert_eq!(8, Point::ORIGIN.neighbours8().count());
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::UpLeft, Direction::DownLeft.turn_right());
+        assert_eq!(Direction::Down, Direction::Up.opposite());
+        assert_eq!(Direction::UpLeft, Direction::DownRight.opposite());
+        assert_eq!(Direction::UpRight, Direction::Up.rotate(1));
+        assert_eq!(Direction::UpLeft, Direction::Up.rotate(-1));
+        assert!(Direction::DownLeft.is_diagonal() && !Direction::Left.is_diagonal());
+
+        for dir in Direction::ALL {
+            assert_eq!(-dir.delta(), dir.opposite().delta());
+            assert_eq!(dir, dir.turn_right().turn_right().turn_right().turn_right());
+        }
+
+        assert_eq!(Some(Direction::Down), Direction::from_arrow('v'));
+        assert_eq!(None, Direction::from_arrow('x'));
+    }
+}
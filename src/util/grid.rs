@@ -0,0 +1,241 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use super::{Error, Point, Result};
+
+
+/// A rectangular map, stored row by row. Positions are `Point`s from the top left corner, a step off the edge is
+/// a `None` from `get`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width:usize,
+    height:usize,
+    cells:Vec<T>,
+}
+
+impl Grid<char> {
+    /// One cell per character, every line must be as long as the first.
+    pub fn parse(input:&str) -> Result<Grid<char>> {
+        Grid::parse_with(input, "unexpected character in map", Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character with `cell`, which returns `None` for characters that don't belong in the map.
+    /// Those are reported with `message`.
+    pub fn parse_with<F: Fn(char) -> Option<T>>(input:&str, message:&str, cell:F) -> Result<Grid<T>> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (index, line) in input.lines().enumerate() {
+            for (pos, ch) in line.char_indices() {
+                match cell(ch) {
+                    Some(value) => cells.push(value),
+                    None => return Err(Error::at(index, line, &line[pos..pos + ch.len_utf8()], message)),
+                }
+            }
+
+            let columns = line.chars().count();
+            match width {
+                None => width = Some(columns),
+                Some(width) if width != columns => {
+                    return Err(Error::at(index, line, line, &format!("expected {} columns like the first line", width)));
+                },
+                Some(_) => {},
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid { width, height, cells }),
+            _ => Err(Error::end_of_input(0, "expected a map")),
+        }
+    }
+
+    /// A grid with the value of `cell` at every position.
+    pub fn from_fn<F: FnMut(Point) -> T>(width:usize, height:usize, mut cell:F) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+            .map(&mut cell)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    pub fn new(width:usize, height:usize, value:T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos:Point) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    fn offset(&self, pos:Point) -> Option<usize> {
+        self.contains(pos).then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// The cell at `pos`, `None` outside the grid.
+    pub fn get(&self, pos:Point) -> Option<&T> {
+        self.offset(pos).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, pos:Point) -> Option<&mut T> {
+        self.offset(pos).map(|offset| &mut self.cells[offset])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The neighbours up, right, down and left of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos:Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbours4().filter(|next| self.contains(*next))
+    }
+
+    /// The neighbours of `pos` inside the grid, diagonals included, clockwise from up.
+    pub fn neighbours8(&self, pos:Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbours8().filter(|next| self.contains(*next))
+    }
+
+    /// The first position holding `value`, row by row.
+    pub fn find(&self, value:&T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Every position holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value:&'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y:usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x:usize) -> impl Iterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U, F: Fn(&T) -> U>(&self, f:F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+/// Cells by `(x, y)`, for loops that count over the width and height. Panics outside the grid.
+impl<T> Index<(usize,usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y):(usize,usize)) -> &T {
+        assert!(x < self.width && y < self.height, "{},{} is outside the {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize,usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y):(usize,usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "{},{} is outside the {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Cells by position. Panics outside the grid.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos:Point) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{} is outside the {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos:Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{} is outside the {}x{} grid", pos, width, height))
+    }
+}
+
+/// The rows on separate lines, without separators between the cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.#\n.S.\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'S'), grid.get(Point::new(1, 1)));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!('#', grid[(2, 0)]);
+        assert_eq!('S', grid[Point::new(1, 1)]);
+        assert_eq!("#.#\n.S.\n", grid.to_string());
+
+        let digits = Grid::parse_with("01\n2x", "expected a digit", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(Error::Parse { day: 0, line: 2, column: 2, text: "x".to_string(), message: "expected a digit".to_string() }, digits);
+        let ragged = Grid::parse("###\n#.\n").unwrap_err();
+        assert_eq!(Error::Parse { day: 0, line: 2, column: 1, text: "#.".to_string(), message: "expected 3 columns like the first line".to_string() }, ragged);
+        assert!(Grid::parse("").is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(vec![Point::new(1, 0), Point::new(0, 1)], grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>());
+        assert_eq!(vec![Point::new(1, 0), Point::new(2, 1), Point::new(1, 2), Point::new(0, 1)], grid.neighbours4(Point::new(1, 1)).collect::<Vec<_>>());
+        assert_eq!(8, grid.neighbours8(Point::new(1, 1)).count());
+        assert_eq!(vec![Point::new(1, 1), Point::new(1, 2), Point::new(0, 2)], grid.neighbours8(Point::new(0, 1)).filter(|pos| pos.y >= 1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_views() {
+        let mut grid = Grid::from_fn(3, 2, |pos| pos.x + 3 * pos.y);
+        assert_eq!(&[3, 4, 5], grid.row(1));
+        assert_eq!(vec![&1, &4], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(2, grid.rows().count());
+        assert_eq!(Some(Point::new(2, 1)), grid.find(&5));
+
+        *grid.get_mut(Point::new(0, 0)).unwrap() = 5;
+        assert_eq!(vec![Point::new(0, 0), Point::new(2, 1)], grid.find_all(&5).collect::<Vec<_>>());
+        assert_eq!("#..\n..#\n", grid.map(|cell| if *cell == 5 { '#' } else { '.' }).to_string());
+    }
+}
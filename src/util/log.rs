@@ -0,0 +1,130 @@
+use std::{fmt, sync::{atomic::{AtomicU8, Ordering}, RwLock}};
+
+
+/// Log levels, from quiet to chatty. Messages go to stderr, so stdout only carries the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn from_u8(value:u8) -> Level {
+        match value {
+            0 | 1 => Level::Error,
+            2 => Level::Warn,
+            3 => Level::Info,
+            4 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    /// `Warn` plus one level per `-v`.
+    pub fn from_verbosity(verbosity:u8) -> Level {
+        Level::from_u8(Level::Warn as u8 + verbosity)
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+
+static LEVEL:AtomicU8 = AtomicU8::new(Level::Warn as u8);
+static TARGETS:RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+pub fn set_level(level:Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+/// Only log these targets, for example `day_03`. An empty list logs everything.
+pub fn set_targets(targets:Vec<String>) {
+    *TARGETS.write().unwrap() = targets;
+}
+
+/// The target of a module path is its last segment, `advent_of_code_2024::util::day_03` logs as `day_03`.
+pub fn target(module_path:&str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+pub fn enabled(level:Level, module_path:&str) -> bool {
+    if level > self::level() {
+        return false;
+    }
+    let targets = TARGETS.read().unwrap();
+    targets.is_empty() || targets.iter().any(|name| name == target(module_path))
+}
+
+pub fn write(level:Level, module_path:&str, args:fmt::Arguments) {
+    eprintln!("[{:<5} {}] {}", level, target(module_path), args);
+}
+
+
+/// Logs with `format!` arguments at the given level, tagged with the calling module.
+/// The arguments are only evaluated when the level and target are enabled.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::Warn, Level::from_verbosity(0));
+        assert_eq!(Level::Debug, Level::from_verbosity(2));
+        assert_eq!(Level::Trace, Level::from_verbosity(9));
+        assert!(Level::Error < Level::Trace);
+        assert_eq!("day_03", target("advent_of_code_2024::util::day_03"));
+        assert_eq!("main", target("main"));
+    }
+}
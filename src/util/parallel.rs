@@ -0,0 +1,58 @@
+use std::{panic, sync::atomic::{AtomicUsize, Ordering}, thread};
+
+
+/// Maps `items` on up to `jobs` worker threads, which take the next item as soon as they are done.
+/// The results come back in the order of `items`, so folding them gives the same answer for any number of jobs.
+pub fn parallel_map<T, R, F>(items:&[T], jobs:usize, f:F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let jobs = jobs.clamp(1, items.len().max(1));
+    if jobs == 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results:Vec<Option<R>> = (0..items.len()).map(|_| None).collect();
+    thread::scope(|scope| {
+        let workers:Vec<_> = (0..jobs)
+            .map(|_| scope.spawn(|| {
+                let mut done = vec![];
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= items.len() {
+                        return done;
+                    }
+                    done.push((index, f(&items[index])));
+                }
+            }))
+            .collect();
+
+        for worker in workers {
+            let done = worker.join().unwrap_or_else(|e| panic::resume_unwind(e));
+            for (index, result) in done {
+                results[index] = Some(result);
+            }
+        }
+    });
+
+    results.into_iter().map(|result| result.expect("every item is mapped")).collect()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let items:Vec<u64> = (0..1000).collect();
+        let expected:Vec<u64> = items.iter().map(|item| item * item).collect();
+        assert_eq!(expected, parallel_map(&items, 4, |item| item * item));
+        assert_eq!(expected, parallel_map(&items, 0, |item| item * item));
+        assert_eq!(Vec::<u64>::new(), parallel_map(&[], 4, |item:&u64| *item));
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+
+
+/// A puzzle constant that can be changed from the command line with `--param name=value`, to run examples and
+/// variants without code edits. Solvers declare theirs in `Solver::params` and read them from `Context::params`,
+/// an unset parameter keeps the value of the puzzle text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name:&'static str,
+    pub help:&'static str,
+    /// The accepted values, inclusive.
+    pub min:u64,
+    pub max:u64,
+}
+
+impl Param {
+    pub const fn new(name:&'static str, min:u64, max:u64, help:&'static str) -> Param {
+        Param { name, help, min, max }
+    }
+}
+
+
+/// Validated parameter values, by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values:BTreeMap<&'static str, u64>,
+}
+
+impl Params {
+    /// Parses `name=value` assignments against the parameters a solver declares.
+    pub fn parse<S: AsRef<str>>(declared:&[Param], assignments:&[S]) -> std::result::Result<Params, String> {
+        let mut params = Params::default();
+        for assignment in assignments {
+            let assignment = assignment.as_ref().trim();
+            let (name, value) = assignment.split_once('=')
+                .ok_or_else(|| format!("expected a parameter like name=value, found '{}'", assignment))?;
+
+            let (name, value) = (name.trim(), value.trim());
+            let Some(param) = declared.iter().find(|param| param.name == name) else {
+                if declared.is_empty() {
+                    return Err(format!("unknown parameter {}, this day has none", name));
+                }
+                let names:Vec<&str> = declared.iter().map(|param| param.name).collect();
+                return Err(format!("unknown parameter {}, expected one of {}", name, names.join(", ")));
+            };
+
+            match value.parse::<u64>() {
+                Ok(value) if (param.min..=param.max).contains(&value) => {
+                    params.values.insert(param.name, value);
+                },
+                _ => return Err(format!("{} must be a number from {} to {}, found '{}'", name, param.min, param.max, value)),
+            }
+        }
+        Ok(params)
+    }
+
+    /// The value of `name` as `T`, `None` when it is unset. The declared range keeps values within `T`.
+    pub fn get<T: TryFrom<u64>>(&self, name:&str) -> Option<T> {
+        self.values.get(name).and_then(|value| T::try_from(*value).ok())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// These values, overridden by the ones set in `other`.
+    pub fn merge(mut self, other:Params) -> Params {
+        self.values.extend(other.values);
+        self
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED:&[Param] = &[
+        Param::new("blinks", 0, 500, "number of blinks"),
+        Param::new("size", 1, 1000, "grid size"),
+    ];
+
+    #[test]
+    fn test_parse() {
+        let params = Params::parse(DECLARED, &["blinks=40", " size = 7 "]).unwrap();
+        assert_eq!(Some(40usize), params.get("blinks"));
+        assert_eq!(Some(7u8), params.get("size"));
+        assert_eq!(None::<u64>, params.get("bytes"));
+        assert!(Params::parse(DECLARED, &[] as &[&str]).unwrap().is_empty());
+
+        assert_eq!(Err("expected a parameter like name=value, found 'blinks'".to_string()), Params::parse(DECLARED, &["blinks"]));
+        assert_eq!(Err("unknown parameter bytes, expected one of blinks, size".to_string()), Params::parse(DECLARED, &["bytes=12"]));
+        assert_eq!(Err("unknown parameter bytes, this day has none".to_string()), Params::parse(&[], &["bytes=12"]));
+        assert_eq!(Err("size must be a number from 1 to 1000, found '0'".to_string()), Params::parse(DECLARED, &["size=0"]));
+        assert_eq!(Err("blinks must be a number from 0 to 500, found '-1'".to_string()), Params::parse(DECLARED, &["blinks=-1"]));
+    }
+
+    #[test]
+    fn test_merge() {
+        let example = Params::parse(DECLARED, &["blinks=6", "size=7"]).unwrap();
+        let merged = example.merge(Params::parse(DECLARED, &["blinks=40"]).unwrap());
+        assert_eq!(Some(40u64), merged.get("blinks"));
+        assert_eq!(Some(7u64), merged.get("size"));
+    }
+}
@@ -0,0 +1,340 @@
+//! Small parser combinators for puzzle lines, like `seq([lit("p="), int(), lit(","), int()])`. Patterns match
+//! greedily from left to right and never backtrack into a repetition, which is all the puzzle formats need.
+//! Integers are captured as they are matched, `capture` captures any other part of a line.
+
+use std::str::FromStr;
+
+use super::{parse_number, Error, Result};
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pattern {
+    /// Exactly this text.
+    Literal(&'static str),
+    /// Digits, with an optional `+` or `-` in front when `signed`.
+    Int { signed:bool },
+    /// All patterns, one after the other.
+    Seq(Vec<Pattern>),
+    /// The first pattern that matches.
+    Alt(Vec<Pattern>),
+    /// The pattern `min` to `max` times, as often as it matches.
+    Repeat { pattern:Box<Pattern>, min:usize, max:Option<usize> },
+    /// The pattern, with the text it matched as a capture.
+    Capture(Box<Pattern>),
+}
+
+pub fn lit(text:&'static str) -> Pattern {
+    Pattern::Literal(text)
+}
+
+/// An integer with an optional sign.
+pub fn int() -> Pattern {
+    Pattern::Int { signed: true }
+}
+
+/// An integer without a sign.
+pub fn uint() -> Pattern {
+    Pattern::Int { signed: false }
+}
+
+pub fn seq<const N: usize>(patterns:[Pattern; N]) -> Pattern {
+    Pattern::Seq(patterns.into())
+}
+
+pub fn alt<const N: usize>(patterns:[Pattern; N]) -> Pattern {
+    Pattern::Alt(patterns.into())
+}
+
+pub fn repeat(pattern:Pattern, min:usize, max:Option<usize>) -> Pattern {
+    Pattern::Repeat { pattern: Box::new(pattern), min, max }
+}
+
+/// The pattern any number of times, also none.
+pub fn many(pattern:Pattern) -> Pattern {
+    repeat(pattern, 0, None)
+}
+
+pub fn optional(pattern:Pattern) -> Pattern {
+    repeat(pattern, 0, Some(1))
+}
+
+/// One or more times `pattern`, separated by `separator`.
+pub fn sep_by(pattern:Pattern, separator:Pattern) -> Pattern {
+    seq([pattern.clone(), many(seq([separator, pattern]))])
+}
+
+pub fn capture(pattern:Pattern) -> Pattern {
+    Pattern::Capture(Box::new(pattern))
+}
+
+/// Every integer in `line`, whatever is around them. A `+` or `-` right before the digits is their sign, unless it
+/// follows a digit, so `3-3` is 3 and 3. Errors point at a number that doesn't fit `T`.
+pub fn ints<T: FromStr>(line_index:usize, line:&str) -> Result<Vec<T>> {
+    int_slices(line).into_iter().map(|token| parse_number(line_index, line, token)).collect()
+}
+
+/// The integers in `line` like `ints`, an error unless there are exactly `N`. The error points at the first extra
+/// number, or at the end of the line when there are too few.
+pub fn ints_n<T: FromStr, const N: usize>(line_index:usize, line:&str) -> Result<[T; N]> {
+    let tokens = int_slices(line);
+    if tokens.len() != N {
+        let end = line.trim_end().len();
+        let text = tokens.get(N).copied().unwrap_or(&line[end..end]);
+        return Err(Error::at(line_index, line, text, &format!("expected {} numbers, found {}", N, tokens.len())));
+    }
+
+    let values = tokens.iter().map(|token| parse_number(line_index, line, token)).collect::<Result<Vec<T>>>()?;
+    Ok(values.try_into().unwrap_or_else(|_| unreachable!("the count was checked")))
+}
+
+/// The integers in `line` as slices of it, like `ints` finds them, for errors that point at one of them.
+pub fn int_slices(line:&str) -> Vec<&str> {
+    int().find_all(0, line).map(|found| {
+        let text = found.text();
+        let after_digit = found.offset() > 0 && line.as_bytes()[found.offset() - 1].is_ascii_digit();
+        if after_digit && !text.starts_with(|c:char| c.is_ascii_digit()) {
+            &text[1..]
+        } else {
+            text
+        }
+    }).collect()
+}
+
+/// A part of a line that a pattern captured, `offset` is the byte offset in the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capture<'a> {
+    pub offset:usize,
+    pub text:&'a str,
+}
+
+/// Where a pattern matched a line, with the captures in the order they start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match<'a> {
+    line_index:usize,
+    line:&'a str,
+    offset:usize,
+    end:usize,
+    captures:Vec<Capture<'a>>,
+}
+
+impl<'a> Match<'a> {
+    /// The byte offset of the match in the line.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The byte offset just after the match.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn text(&self) -> &'a str {
+        &self.line[self.offset..self.end]
+    }
+
+    pub fn captures(&self) -> &[Capture<'a>] {
+        &self.captures
+    }
+
+    /// Capture `n` parsed as `T`, an error at the capture when it doesn't fit.
+    pub fn get<T: FromStr>(&self, n:usize) -> Result<T> {
+        match self.captures.get(n) {
+            Some(capture) => parse_number(self.line_index, self.line, capture.text),
+            None => Err(Error::at(self.line_index, self.line, self.text(), &format!("expected at least {} values", n + 1))),
+        }
+    }
+
+    /// All captures parsed as `T`.
+    pub fn values<T: FromStr>(&self) -> Result<Vec<T>> {
+        (0..self.captures.len()).map(|n| self.get(n)).collect()
+    }
+}
+
+impl Pattern {
+    /// Matches the whole line, trailing whitespace aside. On failure the error points at the furthest place any
+    /// part of the pattern got to, with `message`.
+    pub fn parse<'a>(&self, line_index:usize, line:&'a str, message:&str) -> Result<Match<'a>> {
+        let mut captures = vec![];
+        let mut furthest = 0;
+        let content = line.trim_end();
+        match self.match_at(line, 0, &mut captures, &mut furthest) {
+            Some(end) if end >= content.len() => Ok(Match { line_index, line, offset: 0, end, captures }),
+            Some(end) => Err(Error::at(line_index, line, char_at(line, end), message)),
+            None => Err(Error::at(line_index, line, char_at(line, furthest), message)),
+        }
+    }
+
+    /// Every match in `line` from left to right, skipping over what doesn't match. Matches don't overlap.
+    pub fn find_all<'a, 'p>(&'p self, line_index:usize, line:&'a str) -> impl Iterator<Item = Match<'a>> + 'p
+    where
+        'a: 'p,
+    {
+        let mut offset = 0;
+        std::iter::from_fn(move || {
+            while offset < line.len() {
+                let mut captures = vec![];
+                match self.match_at(line, offset, &mut captures, &mut 0) {
+                    Some(end) if end > offset => {
+                        let found = Match { line_index, line, offset, end, captures };
+                        offset = end;
+                        return Some(found);
+                    },
+                    _ => offset += line[offset..].chars().next().map_or(1, char::len_utf8),
+                }
+            }
+            None
+        })
+    }
+
+    /// Matches at byte `offset` of `line` and returns where the match ends. Captures are added to `captures`, only
+    /// for a match. `furthest` is moved up to the furthest offset any part of the pattern got to.
+    fn match_at<'a>(&self, line:&'a str, offset:usize, captures:&mut Vec<Capture<'a>>, furthest:&mut usize) -> Option<usize> {
+        *furthest = (*furthest).max(offset);
+        match self {
+            Pattern::Literal(text) => {
+                let matching = line[offset..].bytes().zip(text.bytes()).take_while(|(a, b)| a == b).count();
+                if matching == text.len() {
+                    Some(offset + matching)
+                } else {
+                    // Point errors at the first character that differs
+                    *furthest = (*furthest).max(offset + matching);
+                    None
+                }
+            },
+            Pattern::Int { signed } => {
+                let bytes = line.as_bytes();
+                let mut end = offset;
+                if *signed && matches!(bytes.get(end), Some(b'+' | b'-')) {
+                    end += 1;
+                }
+                let digits = bytes[end..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+                if digits == 0 {
+                    return None;
+                }
+                end += digits;
+                captures.push(Capture { offset, text: &line[offset..end] });
+                Some(end)
+            },
+            Pattern::Seq(patterns) => {
+                let mark = captures.len();
+                let mut end = offset;
+                for pattern in patterns {
+                    match pattern.match_at(line, end, captures, furthest) {
+                        Some(next) => end = next,
+                        None => {
+                            captures.truncate(mark);
+                            return None;
+                        },
+                    }
+                }
+                Some(end)
+            },
+            Pattern::Alt(patterns) => patterns.iter().find_map(|pattern| pattern.match_at(line, offset, captures, furthest)),
+            Pattern::Repeat { pattern, min, max } => {
+                let mark = captures.len();
+                let mut end = offset;
+                let mut count = 0;
+                while max.is_none_or(|max| count < max) {
+                    match pattern.match_at(line, end, captures, furthest) {
+                        Some(next) => {
+                            count += 1;
+                            if next == end {
+                                // Matched nothing, it would match nothing forever
+                                break;
+                            }
+                            end = next;
+                        },
+                        None => break,
+                    }
+                }
+
+                if count >= *min {
+                    Some(end)
+                } else {
+                    captures.truncate(mark);
+                    None
+                }
+            },
+            Pattern::Capture(pattern) => {
+                let mark = captures.len();
+                let end = pattern.match_at(line, offset, captures, furthest)?;
+                captures.insert(mark, Capture { offset, text: &line[offset..end] });
+                Some(end)
+            },
+        }
+    }
+}
+
+/// The character at byte `offset` of `line`, or the one `offset` is in the middle of. Empty at the end.
+fn char_at(line:&str, mut offset:usize) -> &str {
+    while !line.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let len = line[offset..].chars().next().map_or(0, char::len_utf8);
+    &line[offset..offset + len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let robot = seq([lit("p="), int(), lit(","), int(), lit(" v="), int(), lit(","), int()]);
+        let found = robot.parse(0, "p=0,4 v=3,-3", "expected a robot").unwrap();
+        assert_eq!(Ok(vec![0, 4, 3, -3]), found.values::<i32>());
+        assert_eq!(Capture { offset: 10, text: "-3" }, found.captures()[3]);
+        assert_eq!("p=0,4 v=3,-3", found.text());
+
+        let error = robot.parse(2, "p=0,4 v=x,-3", "expected a robot").unwrap_err();
+        assert_eq!(Error::Parse { day: 0, line: 3, column: 9, text: "x".to_string(), message: "expected a robot".to_string() }, error);
+        let trailing = robot.parse(0, "p=0,4 v=3,-3 w", "expected a robot").unwrap_err();
+        assert_eq!(Error::Parse { day: 0, line: 1, column: 13, text: " ".to_string(), message: "expected a robot".to_string() }, trailing);
+        assert!(robot.parse(0, "p=0,4 v=3,-3  ", "expected a robot").is_ok());
+        assert_eq!(Err(Error::Parse { day: 0, line: 1, column: 3, text: "-1".to_string(), message: "expected a number".to_string() }),
+            robot.parse(0, "p=-1,4 v=3,-3", "expected a robot").unwrap().get::<u32>(0));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let program = seq([lit("Program: "), sep_by(uint(), lit(","))]);
+        assert_eq!(Ok(vec![0, 1, 5, 4]), program.parse(0, "Program: 0,1,5,4", "").unwrap().values::<u8>());
+        assert!(program.parse(0, "Program: 0,-1", "").is_err());
+
+        let button = seq([lit("Button "), capture(alt([lit("A"), lit("B")])), lit(": X"), int(), lit(", Y"), int()]);
+        let found = button.parse(0, "Button B: X+22, Y+67", "").unwrap();
+        assert_eq!(vec!["B", "+22", "+67"], found.captures().iter().map(|capture| capture.text).collect::<Vec<_>>());
+        assert_eq!(Ok(22u64), found.get(1));
+
+        let spaced = seq([uint(), repeat(lit(" "), 1, None), optional(lit("-")), uint()]);
+        assert_eq!(Ok(vec![3, 4]), spaced.parse(0, "3   -4", "").unwrap().values::<u8>());
+        assert!(seq([uint(), repeat(lit(" "), 2, Some(2)), uint()]).parse(0, "3 4", "").is_err());
+        assert!(many(optional(lit("x"))).parse(0, "xx", "").is_ok());
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(Ok(vec![0, 4, 3, -3]), ints::<i32>(0, "p=0,4 v=3,-3"));
+        assert_eq!(Ok(vec![94, 34]), ints::<u64>(0, "Button A: X+94, Y+34"));
+        assert_eq!(Ok(vec![]), ints::<u8>(0, "Register A:"));
+        assert_eq!(Ok(vec![3, 3]), ints::<u32>(1, "Register A: 3-3"));
+        assert_eq!(Ok(vec![3, -3]), ints::<i32>(1, "3 -3"));
+        assert_eq!(Err(Error::Parse { day: 0, line: 2, column: 1, text: "-3".to_string(), message: "expected a number".to_string() }),
+            ints::<u32>(1, "-3"));
+
+        assert_eq!(Ok([5, 4]), ints_n::<usize, 2>(0, "5,4"));
+        assert_eq!(Err(Error::Parse { day: 0, line: 1, column: 5, text: "3".to_string(), message: "expected 2 numbers, found 3".to_string() }),
+            ints_n::<usize, 2>(0, "5,4,3"));
+        assert_eq!(Err(Error::Parse { day: 0, line: 1, column: 2, text: String::new(), message: "expected 2 numbers, found 1".to_string() }),
+            ints_n::<usize, 2>(0, "5 "));
+    }
+
+    #[test]
+    fn test_find_all() {
+        let mul = seq([lit("mul("), uint(), lit(","), uint(), lit(")")]);
+        let line = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let found:Vec<(usize, Vec<u32>)> = mul.find_all(0, line).map(|found| (found.offset(), found.values().unwrap())).collect();
+        assert_eq!(vec![(1, vec![2, 4]), (29, vec![5, 5]), (53, vec![11, 8]), (62, vec![8, 5])], found);
+        assert_eq!(0, mul.find_all(0, "mul(-1,2)").count());
+    }
+}
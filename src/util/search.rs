@@ -0,0 +1,229 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash};
+
+
+/// What a search found: the distance from the start to every reached node, the neighbours every node is reached
+/// from on a shortest path, and the first goal reached. A search stops at the first goal, so distances and
+/// predecessors are complete for the nodes up to the goal's distance.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    start:N,
+    goal:Option<N>,
+    distances:HashMap<N, u64>,
+    predecessors:HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(start:N) -> Search<N> {
+        Search { start: start.clone(), goal: None, distances: HashMap::from([(start, 0)]), predecessors: HashMap::new() }
+    }
+
+    /// Records that `next` is reached from `node` at `distance`. Returns true when that is shorter than before, so
+    /// `next` must be explored (again).
+    fn reach(&mut self, node:&N, next:N, distance:u64) -> bool {
+        match self.distances.get(&next) {
+            Some(known) if distance > *known => false,
+            Some(known) if distance == *known => {
+                let predecessors = self.predecessors.entry(next).or_default();
+                if !predecessors.contains(node) {
+                    predecessors.push(node.clone());
+                }
+                false
+            },
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            },
+        }
+    }
+
+    /// The first goal reached, `None` when the search ran out of nodes.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn distance(&self, node:&N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, u64> {
+        &self.distances
+    }
+
+    /// Every node reached from the start, the start included.
+    pub fn reachable(&self) -> impl Iterator<Item = &N> {
+        self.distances.keys()
+    }
+
+    /// The nodes `node` is reached from on its shortest paths.
+    pub fn predecessors(&self, node:&N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from the start to `node`, both included. `None` when `node` wasn't reached.
+    pub fn path(&self, node:&N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while *path.last().unwrap() != self.start {
+            path.push(self.predecessors(path.last().unwrap())[0].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on some shortest path from the start to `node`, both included.
+    pub fn on_shortest_paths(&self, node:&N) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack = vec![];
+        if self.distances.contains_key(node) {
+            stack.push(node.clone());
+        }
+
+        while let Some(current) = stack.pop() {
+            if nodes.insert(current.clone()) {
+                stack.extend(self.predecessors(&current).iter().cloned());
+            }
+        }
+        nodes
+    }
+}
+
+
+/// Breadth first search from `start`, every step costs 1. Stops at the first node `is_goal` accepts, pass `|_| false`
+/// to reach everything.
+pub fn bfs<N, I, F, G>(start:N, mut neighbours:F, mut is_goal:G) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let distance = search.distances[&node] + 1;
+        for next in neighbours(&node) {
+            if search.reach(&node, next.clone(), distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Cheapest paths from `start`, `neighbours` gives the next nodes with the cost to step there.
+pub fn dijkstra<N, I, F, G>(start:N, neighbours:F, is_goal:G) -> Search<N>
+where
+    N: Clone + Eq + Hash + Ord,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// Dijkstra guided by `heuristic`, an estimate of the cost left to a goal. The estimate must never be more than
+/// the real cost, nor drop by more than the cost of a step, or the distances are not the shortest.
+pub fn astar<N, I, F, H, G>(start:N, mut neighbours:F, mut heuristic:H, mut is_goal:G) -> Search<N>
+where
+    N: Clone + Eq + Hash + Ord,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    H: FnMut(&N) -> u64,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = BinaryHeap::new();
+    let mut done = HashSet::new();
+    queue.push(Reverse((heuristic(&start), 0, start)));
+
+    while let Some(Reverse((estimate, distance, node))) = queue.pop() {
+        match &search.goal {
+            // Nodes as cheap as the goal may still be predecessors of it
+            Some(goal) if estimate > search.distances[goal] => break,
+            _ => {},
+        }
+        if distance > search.distances[&node] || !done.insert(node.clone()) {
+            continue;
+        }
+
+        if search.goal.is_none() && is_goal(&node) {
+            search.goal = Some(node.clone());
+        }
+
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if search.reach(&node, next.clone(), next_distance) {
+                queue.push(Reverse((next_distance + heuristic(&next), next_distance, next)));
+            }
+        }
+    }
+
+    search
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of nodes 0 to 9 where every node also links to the one 2 further, at the cost of 2 single steps.
+    fn line(node:&u32) -> Vec<(u32, u64)> {
+        let mut next = vec![];
+        if *node < 9 {
+            next.push((node + 1, 1));
+        }
+        if *node < 8 {
+            next.push((node + 2, 2));
+        }
+        next
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(0, |node| line(node).into_iter().map(|(next, _)| next), |node| *node == 4);
+        assert_eq!(Some(&4), search.goal());
+        assert_eq!(Some(2), search.distance(&4));
+        assert_eq!(Some(vec![0, 2, 4]), search.path(&4));
+        assert_eq!(&[2], search.predecessors(&4));
+        assert_eq!(HashSet::from([0, 2, 4]), search.on_shortest_paths(&4));
+
+        let all = bfs(0, |node| line(node).into_iter().map(|(next, _)| next), |_| false);
+        assert_eq!(None, all.goal());
+        assert_eq!(10, all.reachable().count());
+        assert_eq!(None, all.path(&10));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(0, line, |node| *node == 9);
+        assert_eq!(Some(9), search.distance(&9));
+        assert_eq!(Some(&9), search.goal());
+        assert_eq!(10, search.on_shortest_paths(&9).len());
+        let mut predecessors = search.predecessors(&9).to_vec();
+        predecessors.sort();
+        assert_eq!(vec![7, 8], predecessors);
+
+        let unreachable = dijkstra(5, line, |node| *node == 0);
+        assert_eq!(None, unreachable.goal());
+        assert_eq!(5, unreachable.reachable().count());
+    }
+
+    #[test]
+    fn test_astar() {
+        let search = astar(0, line, |node| 9 - *node as u64, |node| *node == 9);
+        assert_eq!(Some(9), search.distance(&9));
+        assert_eq!(10, search.on_shortest_paths(&9).len());
+
+        let path = search.path(&9).unwrap();
+        assert_eq!((Some(&0), Some(&9)), (path.first(), path.last()));
+    }
+}
@@ -0,0 +1,98 @@
+use super::{Answer, Context, Param, Part, Result};
+
+
+/// A single puzzle day. The input is parsed once into `Input` and then handed to the parts.
+/// `Input` may borrow from the puzzle text, so parsers can hand out slices instead of copies.
+pub trait Solver {
+    type Input<'a>;
+
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    /// The puzzle constants that can be set with `--param`, read them with `ctx.params.get`.
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>>;
+
+    fn part1(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer>;
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer>;
+
+    /// Both answers from one parsed input. Override this when part 2 can reuse the work of part 1.
+    fn both(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<(Answer, Answer)> {
+        Ok((self.part1(input, ctx)?, self.part2(input, ctx)?))
+    }
+
+    fn solve(&self, input:&str, part:Part) -> Result<Answer> {
+        self.solve_with(input, part, &Context::default())
+    }
+
+    fn solve_with(&self, input:&str, part:Part, ctx:&Context) -> Result<Answer> {
+        let parsed = self.parse(input).map_err(|e| e.with_day(self.day()))?;
+        let answer = match part {
+            Part::Part1 => self.part1(&parsed, ctx),
+            Part::Part2 => self.part2(&parsed, ctx),
+        };
+        answer.map_err(|e| e.with_day(self.day()))
+    }
+
+    fn solve_both(&self, input:&str) -> Result<(Answer, Answer)> {
+        self.solve_both_with(input, &Context::default())
+    }
+
+    fn solve_both_with(&self, input:&str, ctx:&Context) -> Result<(Answer, Answer)> {
+        let parsed = self.parse(input).map_err(|e| e.with_day(self.day()))?;
+        self.both(&parsed, ctx).map_err(|e| e.with_day(self.day()))
+    }
+}
+
+
+/// Object safe view of a `Solver`, so that days with different input types can share one registry.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn params(&self) -> &'static [Param];
+
+    fn solve(&self, input:&str, part:Part) -> Result<Answer>;
+
+    fn solve_with(&self, input:&str, part:Part, ctx:&Context) -> Result<Answer>;
+
+    fn solve_both(&self, input:&str) -> Result<(Answer, Answer)>;
+
+    fn solve_both_with(&self, input:&str, ctx:&Context) -> Result<(Answer, Answer)>;
+}
+
+impl<S: Solver + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        Solver::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Solver::title(self)
+    }
+
+    fn params(&self) -> &'static [Param] {
+        Solver::params(self)
+    }
+
+    fn solve(&self, input:&str, part:Part) -> Result<Answer> {
+        Solver::solve(self, input, part)
+    }
+
+    fn solve_with(&self, input:&str, part:Part, ctx:&Context) -> Result<Answer> {
+        Solver::solve_with(self, input, part, ctx)
+    }
+
+    fn solve_both(&self, input:&str) -> Result<(Answer, Answer)> {
+        Solver::solve_both(self, input)
+    }
+
+    fn solve_both_with(&self, input:&str, ctx:&Context) -> Result<(Answer, Answer)> {
+        Solver::solve_both_with(self, input, ctx)
+    }
+}
@@ -0,0 +1,110 @@
+use super::{parse_number, Answer, Context, Error, Result, Solver};
+
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input<'a> = Vec<(i32,i32)>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        input.lines().enumerate()
+            .map(|(index, line)| parse(index, line))
+            .collect()
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part2(input))
+    }
+}
+
+fn parse(index:usize, line:&str) -> Result<(i32,i32)> {
+    let numbers:Vec<&str> = line.split_ascii_whitespace().collect();
+    if numbers.len() != 2 {
+        return Err(Error::at(index, line, line, "expected two location ids"));
+    }
+    let left = parse_number(index, line, numbers[0])?;
+    let right = parse_number(index, line, numbers[1])?;
+    Ok((left, right))
+}
+
+fn part1(pairs:&[(i32,i32)]) -> Answer {
+    let mut first:Vec<i32> = pairs.iter().map(|item| item.0).collect();
+    let mut second:Vec<i32> = pairs.iter().map(|item| item.1).collect();
+    first.sort();
+    second.sort();
+
+    first.iter().enumerate()
+        .map(|(index, left)| left.abs_diff(second[index]))
+        .sum::<u32>()
+        .into()
+}
+
+fn part2(pairs:&[(i32,i32)]) -> Answer {
+    let first:Vec<usize> = pairs.iter().map(|item| item.0 as usize).collect();
+    let second:Vec<usize> = pairs.iter().map(|item| item.1 as usize).collect();
+    let mut freq_array = [0;100_000];
+
+    for index in second {
+        freq_array[index] += 1;
+    }
+
+    first.iter().map(|index| index * freq_array[*index])
+        .sum::<usize>()
+        .into()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::*;
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/01_1.txt");
+        assert_eq!(Ok(Answer::Int(11)), Day01.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "3   4
+4   x3";
+        let error = Error::Parse { day: 1, line: 2, column: 5, text: "x3".to_string(), message: "expected a number".to_string() };
+        assert_eq!(Err(error), Day01.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_01.txt");
+        assert_eq!(Ok(Answer::Int(2344935)), Day01.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/01_1.txt");
+        assert_eq!(Ok(Answer::Int(31)), Day01.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_01.txt");
+        assert_eq!(Ok(Answer::Int(27647262)), Day01.solve(input, Part2));
+    }
+}
@@ -0,0 +1,122 @@
+use super::{parse_number, Answer, Context, Error, Result, Solver};
+
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        parse(input.lines().collect())
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part2(input))
+    }
+}
+
+
+fn is_safe(levels:&Vec<u32>) -> bool {
+    let increasing = levels[0] < levels[1];
+    for i in 1..levels.len() {
+        let last = levels[i-1];
+        let next = levels[i];
+        let next_increasing = next > last;
+        let delta = next.abs_diff(last);
+        if delta == 0 || delta > 3 || next_increasing != increasing {
+            return false;
+        }
+    }
+
+    true
+}
+
+
+fn parse(lines:Vec<&str>) -> Result<Vec<Vec<u32>>> {
+    let mut list = vec![];
+    for (index, line) in lines.into_iter().enumerate() {
+        let levels = line.split_ascii_whitespace()
+            .map(|item| parse_number(index, line, item))
+            .collect::<Result<Vec<u32>>>()?;
+        if levels.len() < 2 {
+            return Err(Error::at(index, line, line, "expected at least two levels"));
+        }
+        list.push(levels);
+    }
+    Ok(list)
+}
+
+fn part1(list:&[Vec<u32>]) -> Answer {
+    list.iter().filter(|levels| is_safe(levels)).count().into()
+}
+
+fn part2(list:&[Vec<u32>]) -> Answer {
+    let mut sum = 0;
+    for numbers in list {
+        let mut numbers = numbers.clone();
+        if is_safe(&numbers) {
+            sum += 1;
+        } else {
+            for i in 0..numbers.len() {
+                let removed = numbers.remove(i);
+                let is_safe = is_safe(&numbers);
+                numbers.insert(i, removed);
+                if is_safe {
+                    sum += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    sum.into()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::*;
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/02_1.txt");
+        assert_eq!(Ok(Answer::Int(2)), Day02.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_02.txt");
+        assert_eq!(Ok(Answer::Int(236)), Day02.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/02_1.txt");
+        assert_eq!(Ok(Answer::Int(4)), Day02.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        // 291 => too low
+        let input = include_str!("../../../input/2024/input_02.txt");
+        assert_eq!(Ok(Answer::Int(308)), Day02.solve(input, Part2));
+    }
+}
@@ -0,0 +1,125 @@
+
+use super::{Answer, Context, Result, Solver};
+use super::pattern::{alt, lit, seq, uint};
+use crate::{debug, trace};
+
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+
+/// Sum of the enabled products in `line`. A `mul(...)` with numbers too large for an i32 is noise like the rest.
+fn parse(index:usize, line:&str, part2:bool) -> i32 {
+    let phrase_mult = seq([lit("mul("), uint(), lit(","), uint(), lit(")")]);
+    let phrase = alt([phrase_mult, lit("do()"), lit("don't()")]);
+    let mut result = 0;
+    let mut enabled = true;
+
+    for found in phrase.find_all(index, line) {
+        trace!("found={}, offset={},", found.text(), found.offset());
+        match found.text() {
+            "do()" => {
+                debug!("Do, offset={}", found.offset());
+                enabled = true;
+            },
+            "don't()" => {
+                debug!("DoNot, offset={}", found.offset());
+                enabled = false;
+            },
+            _ => {
+                let (Ok(left), Ok(right)) = (found.get::<i32>(0), found.get::<i32>(1)) else {
+                    trace!("skipped {}, offset={}", found.text(), found.offset());
+                    continue;
+                };
+                if enabled || !part2 {
+                    debug!("mult({},{}), offset={}", left, right, found.offset());
+                    result += left * right;
+                } else {
+                    debug!("----> DISABLED: mult({},{}), offset={}", left, right, found.offset());
+                }
+            },
+        }
+    }
+    result
+
+}
+
+
+fn part1(lines:&[&str]) -> Result<Answer> {
+    let mut result = 0;
+    for (index, line) in lines.iter().enumerate() {
+        result += parse(index, line, false);
+    }
+    Ok(result.into())
+}
+
+fn part2(lines:&[&str]) -> Result<Answer> {
+    let mut result = 0;
+    for (index, line) in lines.iter().enumerate() {
+        result += parse(index, line, true);
+    }
+    Ok(result.into())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::*;
+
+    #[test]
+    fn test1() {
+        let input = include_str!("../../../input/2024/examples/03_1.txt");
+        assert_eq!(Ok(Answer::Int(161)), Day03.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_03.txt");
+        assert_eq!(Ok(Answer::Int(196826776)), Day03.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+        let input = include_str!("../../../input/2024/examples/03_2.txt");
+        assert_eq!(Ok(Answer::Int(48)), Day03.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        // to high => 114961848
+        let input = include_str!("../../../input/2024/input_03.txt");
+        assert_eq!(Ok(Answer::Int(106780429)), Day03.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_overlong_number() {
+        let input = "mul(2,4)mul(99999999999,2)mul(3,3)";
+        assert_eq!(Ok(Answer::Int(17)), Day03.solve(input, Part1));
+    }
+}
@@ -0,0 +1,111 @@
+use super::{Answer, Context, Direction, Grid, Point, Result, Solver};
+
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input<'a> = Grid<char>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        Grid::parse(input)
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part2(input))
+    }
+}
+
+
+fn find_xmas(map:&Grid<char>, pos:Point, dir:Direction) -> bool {
+    "XMAS".chars().enumerate()
+        .all(|(i, ch)| map.get(pos + dir.delta() * i as i32) == Some(&ch))
+}
+
+/// The letter next to `pos` in direction `dir`, `'#'` outside the map.
+fn get_char(map:&Grid<char>, pos:Point, dir:Direction) -> char {
+    map.get(pos.step(dir)).copied().unwrap_or('#')
+}
+
+fn find_mas(map:&Grid<char>, pos:Point) -> bool {
+    if map.get(pos) == Some(&'A') {
+        let up_left: char = get_char(map, pos, Direction::UpLeft);
+        let down_left: char = get_char(map, pos, Direction::DownLeft);
+        let up_right: char = get_char(map, pos, Direction::UpRight);
+        let down_right: char = get_char(map, pos, Direction::DownRight);
+
+        if up_left == 'M' && down_right == 'S' || up_left == 'S' && down_right == 'M' {
+            if up_right == 'M' && down_left == 'S' || up_right == 'S' && down_left == 'M' {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+
+fn part1(map:&Grid<char>) -> Answer {
+    let mut result = 0;
+    
+    for pos in map.positions() {
+        result += Direction::ALL.iter()
+            .filter(|dir| find_xmas(map, pos, **dir))
+            .count();
+    }
+
+    result.into()
+}
+
+fn part2(map:&Grid<char>) -> Answer {
+    map.positions()
+        .filter(|pos| find_mas(map, *pos))
+        .count()
+        .into()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::*;
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/04_1.txt");
+        assert_eq!(Ok(Answer::Int(18)), Day04.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_04.txt");
+        assert_eq!(Ok(Answer::Int(2483)), Day04.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/04_2.txt");
+        assert_eq!(Ok(Answer::Int(9)), Day04.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_04.txt");
+        assert_eq!(Ok(Answer::Int(1925)), Day04.solve(input, Part2));
+    }
+}
@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+use super::{parse_number, Answer, Context, Error, Result, Solver};
+
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input<'a> = (Vec<(u32,u32)>, Vec<Vec<u32>>);
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        parse(input)
+    }
+
+    fn part1(&self, (rules, updates):&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(rules, updates))
+    }
+
+    fn part2(&self, (rules, updates):&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        part2(rules, updates)
+    }
+}
+
+fn parse_rule(index:usize, line:&str)  -> Result<(u32,u32)> {
+    match line.split_once('|') {
+        Some((left, right)) => Ok((parse_number(index, line, left)?, parse_number(index, line, right)?)),
+        None => Err(Error::at(index, line, line, "expected a rule like 47|53")),
+    }
+}
+
+fn parse_update(index:usize, line:&str) -> Result<Vec<u32>> {
+    line.split(',')
+        .map(|item| parse_number(index, line, item))
+        .collect()
+}
+
+
+fn check_update(update:&Vec<u32>, rules:&Vec<(u32,u32)>) -> bool {
+    for i in 0..update.len() - 1 {
+        if !rules.contains(&(update[i], update[i+1])) {
+            return false;
+        }
+    }
+    true
+}
+
+fn sort_and_get_mid(input:&Vec<u32>, rules:&Vec<(u32,u32)>) -> Result<u32> {
+    for (i, a) in input.iter().enumerate() {
+        for b in input[i+1..].iter() {
+            if !rules.contains(&(*a,*b)) && !rules.contains(&(*b,*a)) {
+                return Err(Error::solve(&format!("no rule orders pages {} and {}", a, b)));
+            }
+        }
+    }
+
+    let mut update = input.iter().copied().collect::<Vec<u32>>();
+    update.sort_by(|a,b| {
+        if rules.contains(&(*a,*b)) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    } );
+
+    Ok(update[update.len() / 2])
+}
+
+fn parse(input:&str) -> Result<(Vec<(u32,u32)>, Vec<Vec<u32>>)> {
+    let mut rules = vec![];
+    let mut updates = vec![];
+    let mut in_rules = true;
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            in_rules = false;
+        } else if in_rules {
+            rules.push(parse_rule(index, line)?);
+        } else {
+            updates.push(parse_update(index, line)?);
+        }
+    }
+
+    Ok((rules, updates))
+}
+
+fn part1(rules:&Vec<(u32,u32)>, updates:&[Vec<u32>]) -> Answer {
+    updates.iter()
+        .filter(|update| check_update(update, rules))
+        .map(| update| update[update.len()/2])
+        .sum::<u32>()
+        .into()
+}
+
+
+
+fn part2(rules:&Vec<(u32,u32)>, updates:&[Vec<u32>]) -> Result<Answer> {
+    let mut sum = 0;
+    for update in updates.iter().filter(|update| !check_update(update, rules)) {
+        sum += sort_and_get_mid(update, rules)?;
+    }
+    Ok(sum.into())
+}
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::*;
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/05_1.txt");
+        assert_eq!(Ok(Answer::Int(143)), Day05.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_05.txt");
+        assert_eq!(Ok(Answer::Int(4578)), Day05.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/05_1.txt");
+
+        assert_eq!(Ok(Answer::Int(123)), Day05.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_05.txt");
+        assert_eq!(Ok(Answer::Int(6179)), Day05.solve(input, Part2));
+    }
+}
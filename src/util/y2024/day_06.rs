@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+
+use super::{parallel_map, Answer, Context, Direction, Error, Grid, Point, Result, Solver};
+
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input<'a> = Grid<char>;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        Grid::parse_with(input, "unexpected character in map", |ch| ".#<>^v".contains(ch).then_some(ch))
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part1(input, ctx)
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part2(input, ctx)
+    }
+
+    fn both(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<(Answer, Answer)> {
+        both(input, ctx)
+    }
+}
+
+fn get_start_pos(map:&Grid<char>) -> Result<(Direction, Point)> {
+    map.iter()
+        .find_map(|(pos, ch)| Direction::from_arrow(*ch).map(|dir| (dir, pos)))
+        .ok_or_else(|| Error::solve("no guard found in the map"))
+}
+
+/// The tile in front of the guard and what is on it, `None` when the guard walks off the map.
+fn get_next_pos(dir:Direction, pos:Point, map:&Grid<char>) -> Option<(Point, char)> {
+    let next = pos.step(dir);
+    map.get(next).map(|ch| (next, *ch))
+}
+
+/// The tiles the guard visits before walking off the map, an error when the guard walks in a loop instead.
+fn calc_path(map:&Grid<char>, ctx:&Context) -> Result<HashSet<Point>> {
+    let (mut dir, mut pos) = get_start_pos(map)?;
+    let mut set = HashSet::new();
+    let mut visited = HashSet::new();
+
+    loop {
+        ctx.cancel.check(|| format!("step {} of the guard", visited.len()))?;
+        if !visited.insert((dir, pos)) {
+            return Err(Error::solve(&format!("the guard walks in a loop from {},{}", pos.x, pos.y)));
+        }
+        set.insert(pos);
+
+        match get_next_pos(dir, pos, map) {
+            Some((next, ch)) => {
+                if ch == '#' {
+                    dir = dir.turn_right();
+                } else {
+                    pos = next;
+                }
+            },
+            None => {
+                //println!("x={},y={}", x,y);
+                break;
+            },
+        }
+    }
+
+    Ok(set)
+}
+
+fn part1(map:&Grid<char>, ctx:&Context) -> Result<Answer> {
+    let set = calc_path(map, ctx)?;
+    Ok(set.len().into())
+}
+
+fn part2(map:&Grid<char>, ctx:&Context) -> Result<Answer> {
+    let path= calc_path(map, ctx)?;
+    count_loops(map, path, ctx)
+}
+
+/// Walks from the start with an extra obstacle at `obstacle`, true when the guard ends up in a loop.
+fn is_loop(map:&Grid<char>, start:(Direction, Point), obstacle:Point) -> bool {
+    let (mut dir, mut pos) = start;
+    let mut current_path = HashSet::new();
+
+    loop {
+        if current_path.contains(&(dir, pos)) {
+            // Found cycle
+            return true;
+        } else {
+            current_path.insert((dir, pos));
+        }
+
+        match get_next_pos(dir, pos, map) {
+            Some((next, ch)) => {
+                if ch == '#' || next == obstacle {
+                    dir = dir.turn_right();
+                } else {
+                    pos = next;
+                }
+            },
+            None => {
+                return false;
+            },
+        }
+    }
+}
+
+/// Tries an obstacle on every tile of the guard's original `path` and counts the ones that trap the guard in a loop.
+fn count_loops(map:&Grid<char>, path:HashSet<Point>, ctx:&Context) -> Result<Answer> {
+    let start = get_start_pos(map)?;
+    let mut candidates:Vec<Point> = path.into_iter()
+        .filter(|obstacle| *obstacle != start.1)
+        .collect();
+    candidates.sort();
+
+    let looped = parallel_map(&candidates, ctx.jobs, |obstacle| {
+            ctx.cancel.check(|| format!("obstacle at {},{}", obstacle.x, obstacle.y))?;
+            Ok(is_loop(map, start, *obstacle))
+        })
+        .into_iter()
+        .collect::<Result<Vec<bool>>>()?;
+    let sum = looped.into_iter().filter(|looped| *looped).count();
+
+    Ok(sum.into())
+}
+
+fn both(map:&Grid<char>, ctx:&Context) -> Result<(Answer, Answer)> {
+    let path = calc_path(map, ctx)?;
+    Ok((path.len().into(), count_loops(map, path, ctx)?))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/06_1.txt");
+        assert_eq!(Ok(Answer::Int(41)), Day06.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_06.txt");
+        assert_eq!(Ok(Answer::Int(5239)), Day06.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/06_1.txt");
+        assert_eq!(Ok(Answer::Int(6)), Day06.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_06.txt");
+        assert_eq!(Ok(Answer::Int(1753)), Day06.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_both() {
+        let input = include_str!("../../../input/2024/examples/06_1.txt");
+        assert_eq!(Ok((Answer::Int(41), Answer::Int(6))), Day06.solve_both(input));
+    }
+
+    #[test]
+    fn test_guard_loop() {
+        let input = ".#..\n...#\n.^..\n#...\n..#.";
+        let error = Error::Solve { day: 6, message: "the guard walks in a loop from 1,2".to_string() };
+        assert_eq!(Err(error), Day06.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_jobs() {
+        let input = include_str!("../../../input/2024/examples/06_1.txt");
+        let ctx = Context::default().with_jobs(4);
+        assert_eq!(Ok((Answer::Int(41), Answer::Int(6))), Day06.solve_both_with(input, &ctx));
+    }
+}
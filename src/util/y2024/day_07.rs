@@ -0,0 +1,146 @@
+use super::{parallel_map, parse_number, Answer, Context, Error, Result, Solver};
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input<'a> = Vec<(i64, Vec<i64>)>;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        input.lines().enumerate()
+            .map(|(index, line)| parse_eq(index, line))
+            .collect()
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        Ok(part1(input, ctx.jobs))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        Ok(part2(input, ctx.jobs))
+    }
+}
+
+fn parse_eq(index:usize, line:&str) -> Result<(i64, Vec<i64>)> {
+    let end = &line[line.trim_end().len()..];
+    let (result, terms) = line.split_once(':')
+        .ok_or_else(|| Error::at(index, line, end, "expected an equation like 190: 10 19"))?;
+
+    let result = parse_number(index, line, result)?;
+    let terms = terms.split(' ')
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_number(index, line, item))
+        .collect::<Result<Vec<i64>>>()?;
+    if terms.is_empty() {
+        return Err(Error::at(index, line, end, "expected at least one term after the ':'"));
+    }
+    Ok((result, terms))
+}
+
+
+fn solve_eq(result:i64, terms:&[i64], acc:i64, part2:bool) -> bool {
+    if terms.len() == 0 {
+        if acc == result {
+            return true;
+        } else {
+            return false;
+        }
+    } else {
+        let next = terms[0];
+        if acc == 0 {
+            return solve_eq(result, &terms[1..], next, part2);
+        } else if acc > result {
+            return false;
+        } else {
+            if solve_eq(result, &terms[1..], acc * next, part2) || solve_eq(result, &terms[1..], acc + next, part2) {
+                return true;
+            } else {
+                let num_digits = next.to_string().len() as i64;
+                let mut left = acc;
+                for _ in 0..num_digits {
+                    left *= 10;
+                }
+                return part2 && solve_eq(result, &terms[1..], left + next, part2);
+            }
+        }
+    }
+}
+
+fn calibration(equations:&[(i64, Vec<i64>)], part2:bool, jobs:usize) -> i64 {
+    parallel_map(equations, jobs, |(test_value, terms)| {
+        if solve_eq(*test_value, terms.as_slice(),  0, part2) {
+            *test_value
+        } else {
+            0
+        }
+    }).into_iter().sum()
+}
+
+fn part1(equations:&[(i64, Vec<i64>)], jobs:usize) -> Answer {
+    calibration(equations, false, jobs).into()
+}
+
+fn part2(equations:&[(i64, Vec<i64>)], jobs:usize) -> Answer {
+    calibration(equations, true, jobs).into()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/07_1.txt");
+        assert_eq!(Ok(Answer::Int(3749)), Day07.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_07.txt");
+        assert_eq!(Ok(Answer::Int(1582598718861)), Day07.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/07_1.txt");
+
+        assert_eq!(Ok(Answer::Int(11387)), Day07.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_07.txt");
+        assert_eq!(Ok(Answer::Int(165278151522644)), Day07.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_jobs() {
+        let input = include_str!("../../../input/2024/input_07.txt");
+        let ctx = Context::default().with_jobs(4);
+        assert_eq!(Ok(Answer::Int(1582598718861)), Day07.solve_with(input, Part1, &ctx));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Error::Parse { day: 7, line: 1, column: 9, text: "abc".to_string(), message: "expected a number".to_string() };
+        assert_eq!(Err(error), Day07.solve("190: 10 abc 19", Part1));
+        let error = Error::Parse { day: 7, line: 1, column: 1, text: "190 10".to_string(), message: "expected a number".to_string() };
+        assert_eq!(Err(error), Day07.solve("190 10: 19", Part1));
+        let error = Error::Parse { day: 7, line: 1, column: 10, text: "".to_string(), message: "expected an equation like 190: 10 19".to_string() };
+        assert_eq!(Err(error), Day07.solve("190 10 19", Part1));
+    }
+}
@@ -0,0 +1,127 @@
+use super::{Answer, Context, Grid, Point, Result, Solver};
+
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input<'a> = Grid<char>;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        Grid::parse(input)
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part2(input))
+    }
+}
+
+
+fn get_antennas(map:&Grid<char>) -> Vec<(Point, char)>{
+    map.iter()
+        .filter(|(_, ch)| **ch != '.')
+        .map(|(pos, ch)| (pos, *ch))
+        .collect()
+}
+
+
+/// Anti nodes of the antenna at `pos`, stepping away from it by `delta`.
+fn calc_anti_nodes(map:&Grid<char>, pos:Point, delta:Point, part2:bool) -> Vec<Point> {
+    let mut anti_nodes = vec![];
+    let mut next = pos + delta;
+
+    if part2 {
+        anti_nodes.push(pos);
+    }
+    
+    while map.contains(next) {
+        if !anti_nodes.contains(&next) {
+            anti_nodes.push(next);
+        }
+        next += delta;
+
+        if !part2 {
+            break;
+        }
+    }
+    anti_nodes
+}
+
+fn get_anti_nodes(map:&Grid<char>, antennas:&[(Point, char)], part2:bool) -> Vec<Point> {
+    let mut anti_nodes = vec![];
+    for i in 0..antennas.len() {
+        let (pos, curr) = antennas[i];
+        for j in i+1..antennas.len() {
+            let (other_pos, other) = antennas[j];
+            if other == curr {
+                calc_anti_nodes(map, pos, pos - other_pos, part2)
+                    .into_iter().for_each(|node| anti_nodes.push(node));
+
+                calc_anti_nodes(map, other_pos, other_pos - pos, part2)
+                    .into_iter().for_each(|node| anti_nodes.push(node));   
+            }
+        }
+    }
+
+    anti_nodes.sort();
+    anti_nodes.dedup();
+    anti_nodes
+}
+
+
+fn part1(map:&Grid<char>) -> Answer {
+    let antennas = get_antennas(map);
+    let anti_nodes = get_anti_nodes(map, &antennas, false);
+    anti_nodes.len().into()    
+}
+
+fn part2(map:&Grid<char>) -> Answer {
+    let antennas = get_antennas(map);
+    let anti_nodes = get_anti_nodes(map, &antennas, true);
+    anti_nodes.len().into()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+        let input = include_str!("../../../input/2024/examples/08_1.txt");
+        assert_eq!(Ok(Answer::Int(14)), Day08.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let real_input = include_str!("../../../input/2024/input_08.txt");
+        assert_eq!(Ok(Answer::Int(289)), Day08.solve(real_input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+        let input = include_str!("../../../input/2024/examples/08_1.txt");
+        assert_eq!(Ok(Answer::Int(34)), Day08.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let real_input = include_str!("../../../input/2024/input_08.txt");
+        assert_eq!(Ok(Answer::Int(1030)), Day08.solve(real_input, Part2));
+    }
+}
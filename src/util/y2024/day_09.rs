@@ -1,32 +1,52 @@
 use std::collections::VecDeque;
 
-use super::Part;
+use super::{Answer, Context, Error, Result, Solver};
 
 
-pub fn solve(input : String, part: Part) -> String {
+pub struct Day09;
 
-    let lines = input.lines().collect();
-    match part {
-        Part::Part1 => part1(lines),
-        Part::Part2 => part2(lines)
+impl Solver for Day09 {
+    type Input<'a> = VecDeque<Block>;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        match input.lines().next() {
+            Some(line) if !line.is_empty() => parse_line(line),
+            _ => Err(Error::end_of_input(0, "expected a disk map")),
+        }
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part2(input, ctx)
     }
 }
 
 
 #[derive(Debug, Clone, Copy)]
-enum Block {
+pub enum Block {
     FileBlock(u64),
     Empty,    
 }
 
 
-fn parse_line(line:&str) -> VecDeque<Block> {
-    let numbers:Vec<char> = line.chars().collect();
+fn parse_line(line:&str) -> Result<VecDeque<Block>> {
     let mut queue = VecDeque::new();
     let mut file_id = 0;
     let mut is_file_block = true;
-    for ch in numbers {
-        let block_size = ch.to_digit(10).unwrap();
+    for (pos, ch) in line.char_indices() {
+        let block_size = ch.to_digit(10)
+            .ok_or_else(|| Error::at(0, line, &line[pos..pos+ch.len_utf8()], "expected a digit"))?;
 
         for _ in 0..block_size {
             if is_file_block {
@@ -43,7 +63,7 @@ fn parse_line(line:&str) -> VecDeque<Block> {
         is_file_block = !is_file_block;
     }
 
-    queue
+    Ok(queue)
 }
 
 
@@ -170,20 +190,17 @@ fn compact_v2(file_id:u64, data:&mut Vec<Block>) {
 }
 
 
-fn part1(lines:Vec<&str>) -> String {
-    let line = lines.first().unwrap();
-    let content = parse_line(line);
-    let file_blocks = compact(content);
-    calc_checksum(&file_blocks).to_string()
+fn part1(content:&VecDeque<Block>) -> Answer {
+    let file_blocks = compact(content.clone());
+    calc_checksum(&file_blocks).into()
 }
 
-fn part2(lines:Vec<&str>) -> String {
-    let line: &&str = lines.first().unwrap();
-    let mut data = parse_line(line).iter().copied().collect();
+fn part2(content:&VecDeque<Block>, ctx:&Context) -> Result<Answer> {
+    let mut data = content.iter().copied().collect();
     let mut current_file_id = get_max_file_id(&data);
 
     loop {
-
+        ctx.cancel.check(|| format!("file {}", current_file_id))?;
         compact_v2(current_file_id, &mut data);  
         if current_file_id == 0 {
             break;
@@ -192,7 +209,7 @@ fn part2(lines:Vec<&str>) -> String {
         }
     }
 
-    calc_checksum(&data.iter().copied().collect()).to_string()
+    Ok(calc_checksum(&data.iter().copied().collect()).into())
 }
 
 
@@ -206,25 +223,25 @@ mod tests {
 
     #[test]
     fn test1() {
-        let input = "2333133121414131402";
-        assert_eq!("1928", solve(input.to_string(), Part1));
+        let input = include_str!("../../../input/2024/examples/09_1.txt");
+        assert_eq!(Ok(Answer::Int(1928)), Day09.solve(input, Part1));
     }
 
     #[test]
     fn test_part1() {
-        let input = include_str!("../../input/input_09.txt");
-        assert_eq!("6262891638328", solve(input.to_string(), Part1));
+        let input = include_str!("../../../input/2024/input_09.txt");
+        assert_eq!(Ok(Answer::Int(6262891638328)), Day09.solve(input, Part1));
     }
 
     #[test]
     fn test2() {
-        let input = "2333133121414131402";
-        assert_eq!("2858", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/examples/09_1.txt");
+        assert_eq!(Ok(Answer::Int(2858)), Day09.solve(input, Part2));
     }
 
     //#[test]
     fn _test_part2() {
-        let input = include_str!("../../input/input_09.txt");
-        assert_eq!("6287317016845", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/input_09.txt");
+        assert_eq!(Ok(Answer::Int(6287317016845)), Day09.solve(input, Part2));
     }
 }
@@ -0,0 +1,151 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{bfs, parallel_map, Answer, Context, Grid, Point, Result, Solver};
+
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input<'a> = Grid<u8>;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        parse(input)
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        Ok(part1(input, ctx.jobs))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        Ok(part2(input, ctx.jobs))
+    }
+}
+
+/// Heights, '.' marks impassable tiles in the examples.
+fn parse(input:&str) -> Result<Grid<u8>> {
+    Grid::parse_with(input, "expected a height or '.'", |ch| match ch {
+        '0'..='9' => Some(ch as u8 - b'0'),
+        '.' => Some(b'.'),
+        _ => None,
+    })
+}
+
+
+fn get_trail_heads(map:&Grid<u8>)-> Vec<(Point, u8)> {
+    map.find_all(&0).map(|pos| (pos, 0)).collect()
+}
+
+/// The number of height 9 tiles that can be reached from `start`.
+fn find_height(start:(Point, u8), map:&Grid<u8>)  -> u32 {
+    bfs(start, |pos| get_next_pos(*pos, map), |_| false)
+        .reachable()
+        .filter(|(_, height)| *height == 9)
+        .count() as u32
+}
+
+
+fn get_next_pos((pos, height):(Point, u8), map:&Grid<u8>) -> Vec<(Point, u8)> {
+
+    // Get neightbours
+    let next_height = height + 1;
+    map.neighbours4(pos)
+        .filter(|next| map.get(*next) == Some(&next_height))
+        .map(|next| (next, next_height))
+        .collect()
+}
+
+fn find_height_distinct(start:(Point, u8), map:&Grid<u8>)  -> u32 {
+    let mut result = 0;
+    let mut queue = VecDeque::new();
+    let mut visited:HashSet<Vec<Point>> = HashSet::new();
+    let path:Vec<Point> = vec![start.0];
+    queue.push_back((start, path));
+
+    while !queue.is_empty() {
+        let (pos, path) = queue.pop_front().unwrap();
+        let height = pos.1;
+        if visited.contains(&path) {
+            continue;
+        } else {
+            visited.insert(path.iter().copied().collect());
+        }
+
+        if height == 9 {
+            // Found height
+            result += 1;
+            continue;
+        }
+
+        // Get neightbours
+        let next_nodes = get_next_pos(pos, map);
+        for next in next_nodes {
+            let mut next_path:Vec<Point> = path.iter().copied().collect();
+            next_path.push(next.0);
+            queue.push_back((next, next_path));
+        } 
+    }
+
+    result
+}
+
+
+fn part1(map:&Grid<u8>, jobs:usize) -> Answer {
+    let start_positions = get_trail_heads(map);
+    parallel_map(&start_positions, jobs, |start| find_height(*start, map))
+        .into_iter()
+        .sum::<u32>()
+        .into()
+
+}
+
+fn part2(map:&Grid<u8>, jobs:usize) -> Answer {
+    let start_positions = get_trail_heads(map);
+    parallel_map(&start_positions, jobs, |start| find_height_distinct(*start, map))
+        .into_iter()
+        .sum::<u32>()
+        .into()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/10_1.txt");
+        assert_eq!(Ok(Answer::Int(36)), Day10.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_10.txt");
+        assert_eq!(Ok(Answer::Int(667)), Day10.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/10_1.txt");
+        assert_eq!(Ok(Answer::Int(81)), Day10.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_10.txt");
+        assert_eq!(Ok(Answer::Int(1344)), Day10.solve(input, Part2));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use super::{parse_number, Answer, Context, Error, Param, Result, Solver};
+
+pub struct Day11;
+
+const PARAMS:&[Param] = &[
+    Param::new("blinks", 0, 500, "number of blinks, 25 for part 1 and 75 for part 2"),
+];
+
+impl Solver for Day11 {
+    type Input<'a> = Vec<u64>;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        match input.lines().next() {
+            Some(line) => parse(line),
+            None => Err(Error::end_of_input(0, "expected a line of stones")),
+        }
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part1(input, ctx.params.get("blinks").unwrap_or(25))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part2(input, ctx.params.get("blinks").unwrap_or(75))
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+enum Stones {
+    Single(u64),
+    Two(u64,u64)
+}
+
+
+
+/// The stones `stone` turns into after one blink, an error when the new stone doesn't fit in a u64.
+fn split_stone(stone:u64) -> Result<Stones> {
+        if stone == 0 {
+            return Ok(Stones::Single(1));
+        } else {
+            // digits
+            let digits = stone.ilog10() + 1;
+            if digits % 2 == 0 {
+                let mult:u64 = 10_u64.pow(digits / 2);
+                let left = stone / mult;
+                let right = stone - left * mult;
+                return Ok(Stones::Two(left, right));
+            } else {
+                let single = stone.checked_mul(2024)
+                    .ok_or_else(|| Error::solve(&format!("stone {} times 2024 doesn't fit in a u64", stone)))?;
+                return Ok(Stones::Single(single));
+            }
+        }
+}
+
+fn parse(line:&str) -> Result<Vec<u64>> {
+    line.split_ascii_whitespace()
+        .map(|item| parse_number(0, line, item))
+        .collect()
+}
+
+
+fn process_blinks(stones:&Vec<u64>, blinks:u32) -> Result<u64> {
+    let mut cache:HashMap<(u64,u32),u64> = HashMap::new();
+    stones.iter()
+        .try_fold(0u64, |sum, stone| checked_add(sum, process_blinks_recursive(*stone, blinks, &mut cache)?))
+}
+
+fn checked_add(left:u64, right:u64) -> Result<u64> {
+    left.checked_add(right).ok_or_else(|| Error::solve("too many stones to count in a u64"))
+}
+
+/// The number of stones `stone` turns into, an error when a stone or the count doesn't fit in a u64.
+fn process_blinks_recursive(stone:u64, blinks:u32, cache:&mut HashMap<(u64,u32),u64>) -> Result<u64> {
+    if blinks == 0 {
+        return Ok(1);
+    }
+
+    let key = (stone, blinks);
+    if cache.contains_key(&key) {
+        return Ok(*cache.get(&key).unwrap());
+    }
+
+    let result = match split_stone(stone)? {
+        Stones::Single(single) => {
+            process_blinks_recursive(single, blinks-1, cache)?
+        },
+        Stones::Two(left, right) => {
+            let left_result: u64 = process_blinks_recursive(left, blinks-1, cache)?;
+            let right_result: u64 = process_blinks_recursive(right, blinks-1, cache)?;
+            checked_add(left_result, right_result)?
+        },
+    };
+
+    cache.insert(key, result);
+    Ok(result)
+}
+
+
+fn part1(stones:&Vec<u64>, blinks:u32) -> Result<Answer> {
+    let result = process_blinks(stones, blinks)?;
+    Ok(result.into())
+}
+
+fn part2(stones:&Vec<u64>, blinks:u32) -> Result<Answer> {
+    let result = process_blinks(stones, blinks)?;
+    Ok(result.into())
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+    use super::super::Params;
+
+
+    #[test]
+    fn test1() {
+        let input = include_str!("../../../input/2024/examples/11_1.txt");
+        assert_eq!(Ok(Answer::Int(55312)), Day11.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_11.txt");
+        assert_eq!(Ok(Answer::Int(193269)), Day11.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+        let input = include_str!("../../../input/2024/examples/11_1.txt");
+        assert_eq!(Ok(Answer::Int(65601038650482)), Day11.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_11.txt");
+        assert_eq!(Ok(Answer::Int(228449040027793)), Day11.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_params() {
+        let input = include_str!("../../../input/2024/examples/11_2.txt");
+        let ctx = Context::default().with_params(Params::parse(Day11.params(), &["blinks=6"]).unwrap());
+        assert_eq!(Ok(Answer::Int(22)), Day11.solve_with(input, Part1, &ctx));
+        assert_eq!(Ok(Answer::Int(22)), Day11.solve_with(input, Part2, &ctx));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = include_str!("../../../input/2024/examples/11_2.txt");
+        let ctx = Context::default().with_params(Params::parse(Day11.params(), &["blinks=500"]).unwrap());
+        assert_eq!(Err(Error::Solve { day: 11, message: "too many stones to count in a u64".to_string() }), Day11.solve_with(input, Part2, &ctx));
+    }
+
+    #[test]
+    fn test_large_stone() {
+        let error = Error::Solve { day: 11, message: "stone 1000000000000000000 times 2024 doesn't fit in a u64".to_string() };
+        assert_eq!(Err(error), Day11.solve("1000000000000000000", Part1));
+    }
+}
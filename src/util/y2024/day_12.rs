@@ -1,43 +1,34 @@
-use std::{collections::{HashMap, VecDeque}, vec};
+use std::{collections::VecDeque, vec};
 
-use super::Part;
+use super::{Answer, Context, Direction, Grid, Point, Result, Solver};
+use crate::{debug, trace};
 
 
-pub fn solve(input : String, part: Part) -> String {
+pub struct Day12;
 
-    let lines = input.lines().collect();
-    match part {
-        Part::Part1 => part1(lines),
-        Part::Part2 => part2(lines)
-    }
-}
+impl Solver for Day12 {
+    type Input<'a> = Map;
 
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Position {
-    x:i32,
-    y:i32,
-}
-
-impl Position {
-    fn create(x_in:usize, y_in:usize) -> Position {
-        Position { x: x_in as i32 , y: y_in as i32 }
+    fn day(&self) -> u8 {
+        12
     }
 
-    fn up(&self) -> Position {
-        Position{x:self.x, y:self.y-1}
+    fn title(&self) -> &'static str {
+        "Garden Groups"
     }
 
-    fn down(&self) -> Position {
-        Position{x:self.x, y:self.y+1}
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        let mut map = Map::create(Grid::parse(input)?);
+        map.explore();
+        Ok(map)
     }
 
-    fn left(&self) -> Position {
-        Position{x:self.x-1, y:self.y}
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(input))
     }
 
-    fn right(&self) -> Position {
-        Position{x:self.x+1, y:self.y}
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part2(input))
     }
 }
 
@@ -45,18 +36,18 @@ impl Position {
 #[derive(Debug)]
 struct Region {
     region_type:char,
-    positions:Vec<Position>,
+    positions:Vec<Point>,
 }
 
 
 impl Region {
 
     fn print(&self) {
-        println!("Region[type:{}, pos:{:?}]", self.region_type, self.positions);
+        debug!("Region[type:{}, pos:{:?}]", self.region_type, self.positions);
     }
 
 
-    fn calc_corner(&self) -> Vec<Position> {
+    fn calc_corner(&self) -> Vec<Point> {
         let xmin = self.positions.iter().map(|p| p.x).min().unwrap();
         let xmax = self.positions.iter().map(|p| p.x).max().unwrap()+1;
         let ymin = self.positions.iter().map(|p| p.y).min().unwrap();
@@ -65,10 +56,10 @@ impl Region {
         let mut corners = vec![];
         for y in ymin..=ymax {
             for x in xmin..=xmax {
-                let down_right = Position{x,y};
-                let up_right = down_right.up();
-                let down_left = down_right.left();
-                let up_left = up_right.left();
+                let down_right = Point::new(x, y);
+                let up_right = down_right.step(Direction::Up);
+                let down_left = down_right.step(Direction::Left);
+                let up_left = down_right.step(Direction::UpLeft);
 
                 let corner_pos = [&up_left, &up_right, &down_left, &down_right];
                 let bool_vec:Vec<bool> = corner_pos.iter().map(|item| self.contains_position(item))
@@ -76,7 +67,7 @@ impl Region {
                 let count = bool_vec.iter().filter(|item| **item).count();
 
 
-                println!("x={}, y={}, count = {}", x, y, count);
+                trace!("x={}, y={}, count = {}", x, y, count);
 
                 if count > 0 && count < 4 {
                     // possible corner
@@ -98,7 +89,7 @@ impl Region {
         corners
     }
 
-    fn contains_position(&self, pos:&Position) -> bool {
+    fn contains_position(&self, pos:&Point) -> bool {
         self.positions.contains(pos)
     }
 
@@ -111,10 +102,9 @@ impl Region {
         sum
 
     }
-    fn calc_perimiter_for_pos(&self, pos:&Position) -> u32 {
+    fn calc_perimiter_for_pos(&self, pos:&Point) -> u32 {
         let mut perimiter = 0;
-        let adjacent_pos = vec![pos.left(), pos.right(), pos.up(), pos.down()];
-        for pos in adjacent_pos {
+        for pos in pos.neighbours4() {
             if !self.contains_position(&pos) {
                 perimiter += 1;
             }
@@ -128,33 +118,21 @@ impl Region {
     
 }
 
-struct Map {
-    width:i32,
-    height:i32,
-    grid:HashMap<Position, char>,
+pub struct Map {
+    grid:Grid<char>,
     regions:Vec<Region>,
 }
 
 impl Map {
-    fn create(lines:Vec<&str>) -> Map {
-        let mut grid = HashMap::new();
-        let height = lines.len() as i32;
-        let width = lines[0].len() as i32;
-        for y in 0..lines.len() {
-            let chars:Vec<char> = lines[y].chars().collect();
-            for x in 0 ..chars.len() {
-                let ch = chars[x];
-                grid.insert(Position::create(x, y), ch);
-            }
-        }
-        Map{width, height, grid:grid, regions:vec![]}
+    fn create(grid:Grid<char>) -> Map {
+        Map{grid, regions:vec![]}
     }
 
     fn explore(&mut self) {
-        for x in 0..self.width {
-            for y in 0..self.height {
-                let pos = Position{x,y};
-                let region_type = *self.grid.get(&pos).unwrap();
+        for x in 0..self.grid.width() {
+            for y in 0..self.grid.height() {
+                let pos = Point::new(x as i32, y as i32);
+                let region_type = self.grid[(x, y)];
 
                 // If it does not belong to a region
                 if !self.is_in_reqion(&pos, region_type) {
@@ -168,8 +146,8 @@ impl Map {
         }
     }
     
-    fn explore_region(&self, region_type:char, pos:Position) -> Region {
-        let mut queue:VecDeque<Position> = VecDeque::new();
+    fn explore_region(&self, region_type:char, pos:Point) -> Region {
+        let mut queue:VecDeque<Point> = VecDeque::new();
         let mut positions = vec![];
         queue.push_back(pos);
 
@@ -179,15 +157,12 @@ impl Map {
                 continue;
             }
 
-            match self.grid.get(&current) {
+            match self.grid.get(current) {
                 Some(next_region_type) => {
                     if *next_region_type == region_type {
 
                         // Add neighbors
-                        queue.push_back(current.down());
-                        queue.push_back(current.up());
-                        queue.push_back(current.left());
-                        queue.push_back(current.right());
+                        queue.extend(current.neighbours4());
 
                         // Add current to region
                         positions.push(current);
@@ -204,7 +179,7 @@ impl Map {
 
     }
 
-    fn is_in_reqion(&self, pos:&Position, region_type:char) -> bool {
+    fn is_in_reqion(&self, pos:&Point, region_type:char) -> bool {
         for region in self.regions.iter() {
             if region.region_type == region_type && region.contains_position(pos) {
                 return true;
@@ -214,34 +189,30 @@ impl Map {
     }
 }
 
-fn part1(lines:Vec<&str>) -> String {
-    let mut map = Map::create(lines);
+fn part1(map:&Map) -> Answer {
     let mut sum = 0;
-    map.explore();
 
     for region in map.regions.iter() {
         sum += region.calc_perimeter() * region.calc_area();
     }
 
-    sum.to_string()
+    sum.into()
 }
 
-fn part2(lines:Vec<&str>) -> String {
-    let mut map = Map::create(lines);
+fn part2(map:&Map) -> Answer {
     let mut sum = 0;
-    map.explore();
 
     for region in map.regions.iter() {
 
         let corners = region.calc_corner();
-        println!("Region:{:?}", region);
-        println!("corners:{:?}", corners);
-        println!("=====> corners:{}", corners.len());
+        debug!("Region:{:?}", region);
+        trace!("corners:{:?}", corners);
+        debug!("=====> corners:{}", corners.len());
         sum += corners.len() * region.calc_area() as usize;
         
     }
 
-    sum.to_string()
+    sum.into()
 }
 
 
@@ -256,74 +227,49 @@ mod tests {
     #[test]
     fn test1() {
 
-        let input = "RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE";
-        assert_eq!("1930", solve(input.to_string(), Part1));
+        let input = include_str!("../../../input/2024/examples/12_1.txt");
+        assert_eq!(Ok(Answer::Int(1930)), Day12.solve(input, Part1));
     }
 
     #[test]
     fn test_part1() {
-        let input = include_str!("../../input/input_12.txt");
-        assert_eq!("1473408", solve(input.to_string(), Part1));
+        let input = include_str!("../../../input/2024/input_12.txt");
+        assert_eq!(Ok(Answer::Int(1473408)), Day12.solve(input, Part1));
     }
 
     #[test]
     fn test2() {
 
-        let input = "OOOOO
-OXOXO
-OOOOO
-OXOXO
-OOOOO";
-        assert_eq!("436", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/examples/12_2.txt");
+        assert_eq!(Ok(Answer::Int(436)), Day12.solve(input, Part2));
     }
 
     #[test]
     fn test21() {
 
-        let input = "AAAA
-BBCD
-BBCC
-EEEC";
-        assert_eq!("80", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/examples/12_3.txt");
+        assert_eq!(Ok(Answer::Int(80)), Day12.solve(input, Part2));
     }
 
     #[test]
     fn test22() {
 
-        let input = "EEEEE
-EXXXX
-EEEEE
-EXXXX
-EEEEE";
-        assert_eq!("236", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/examples/12_4.txt");
+        assert_eq!(Ok(Answer::Int(236)), Day12.solve(input, Part2));
     }
 
 
    //#[test]
     fn test23() {
 
-        let input = "AAAAAA
-AAABBA
-AAABBA
-ABBAAA
-ABBAAA
-AAAAAA";
-        assert_eq!("368", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/examples/12_5.txt");
+        assert_eq!(Ok(Answer::Int(368)), Day12.solve(input, Part2));
     }
 
     //#[test]
     fn test_part2() {
         // too low=883914
-        let input = include_str!("../../input/input_12.txt");
-        assert_eq!("2", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/input_12.txt");
+        assert_eq!(Ok(Answer::Int(2)), Day12.solve(input, Part2));
     }
 }
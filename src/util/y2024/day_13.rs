@@ -0,0 +1,215 @@
+
+use super::{parallel_map, Answer, Context, Error, Param, Result, Solver};
+use super::pattern::ints_n;
+use crate::{debug, trace};
+
+
+pub struct Day13;
+
+const PARAMS:&[Param] = &[
+    Param::new("offset", 0, 1_000_000_000_000_000, "added to the prize coordinates in part 2, 10000000000000"),
+];
+
+/// Button A, button B and the prize, as (x, y) pairs.
+type Machine = ((u64,u64),(u64,u64),(u64,u64));
+
+impl Solver for Day13 {
+    type Input<'a> = Vec<Machine>;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        let lines:Vec<&str> = input.lines().collect();
+        lines.chunks(4).enumerate()
+            .map(|(chunk, lines)| parse(chunk * 4, lines))
+            .collect()
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part1(input, ctx)
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part2(input, ctx.params.get("offset").unwrap_or(10000000000000), ctx)
+    }
+}
+
+
+fn get_numeric_pair(index:usize, line:&str) -> Result<(u64,u64)> {
+    let [x, y] = ints_n(index, line)?;
+    Ok((x, y))
+}
+
+fn parse(first_index:usize, lines:&[&str]) -> Result<Machine> {
+    if lines.len() < 3 {
+        return Err(Error::end_of_input(first_index + lines.len(), "expected two buttons and a prize"));
+    }
+    let button_a = get_numeric_pair(first_index, lines[0])?;
+    let button_b = get_numeric_pair(first_index + 1, lines[1])?;
+    let target = get_numeric_pair(first_index + 2, lines[2])?;
+    Ok((button_a, button_b, target))
+}
+
+
+/// Tries the presses of A from 1 up, stops with an error when `ctx` is cancelled.
+fn find_target_2(button_a:(u64,u64), button_b:(u64,u64), target:(u64,u64), ctx:&Context) -> Result<Option<(u64,u64)>>{
+    let (x_a, y_a) = button_a;
+    let (x_b, y_b) = button_b;
+    let (x_t, y_t) = target;
+
+
+    debug!("{:?},{:?},{:?}", button_a, button_b, target);
+    let mut i  = 1;
+    let mut step_size = 1;
+    let mut last_mult = 0;
+    let mut tries:u64 = 0;
+
+    loop {
+        if tries % 1_000_000 == 0 {
+            ctx.cancel.check(|| format!("A pressed {} times", i))?;
+        }
+        tries += 1;
+
+        let step_x = x_a * i;
+        let step_y = y_a * i;
+
+        if step_x > x_t || step_y > y_t {
+            break;
+        }
+
+        let dx = x_t - x_a * i;
+        let dy = y_t - y_a * i;
+
+        if dx % x_b == 0  && dy % y_b == 0 {
+
+            if last_mult == 0 {
+                last_mult = i;
+            } else if step_size == 1 {
+                step_size = i-last_mult;
+            }
+
+            trace!("mult a:{}, mult b1:{}, mult b2:{}", i, dx/x_b, dy/y_b);
+
+            if dx / x_b == dy / y_b {
+                debug!("mult a:{}, mult:{}", i, dx/x_b);
+                return Ok(Some((i, dx/x_b)));
+            } 
+        }
+
+        i += step_size;
+    }
+
+    Ok(None)
+}
+
+
+
+fn find_target_3(button_a:(u64,u64), button_b:(u64,u64), target:(u64,u64)) {
+    let (x_a, y_a) = button_a;
+    let (x_b, y_b) = button_b;
+    let (x_t, y_t) = target;
+
+
+
+}
+
+/// Tokens needed to win the prize of one machine, 0 when it can't be won.
+fn tokens(machine:&Machine, offset:u64, ctx:&Context) -> Result<u64> {
+    let &(button_a, button_b, target) = machine;
+    let new_target = (target.0 + offset, target.1 + offset);
+    match find_target_2(button_a, button_b, new_target, ctx)? {
+        Some((a,b)) => {
+            let tokens = a*3 + b;
+            debug!("tokens={}", tokens);
+            Ok(tokens)
+        },
+        None => Ok(0),
+    }
+}
+
+fn part1(machines:&[Machine], ctx:&Context) -> Result<Answer> {
+    let result:u64 = parallel_map(machines, ctx.jobs, |machine| tokens(machine, 0, ctx))
+        .into_iter()
+        .sum::<Result<u64>>()?;
+    Ok(result.into())
+}
+
+fn part2(machines:&[Machine], offset:u64, ctx:&Context) -> Result<Answer> {
+    let result:u64 = parallel_map(machines, ctx.jobs, |machine| tokens(machine, offset, ctx))
+        .into_iter()
+        .sum::<Result<u64>>()?;
+    Ok(result.into())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+    use super::super::Params;
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/13_1.txt");
+        assert_eq!(Ok(Answer::Int(480)), Day13.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_13.txt");
+        assert_eq!(Ok(Answer::Int(29711)), Day13.solve(input, Part1));
+    }
+
+    //#[test]
+    fn _test2() {
+
+        let input = include_str!("../../../input/2024/examples/13_1.txt");
+
+        assert_eq!(Ok(Answer::Int(0)), Day13.solve(input, Part2));
+    }
+
+    //#[test]
+    fn _test_part2() {
+        // too low => 82525073961064
+        let input = include_str!("../../../input/2024/input_13.txt");
+        assert_eq!(Ok(Answer::Int(2)), Day13.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_params() {
+        // Without the offset part 2 is part 1
+        let input = include_str!("../../../input/2024/examples/13_1.txt");
+        let ctx = Context::default().with_params(Params::parse(Day13.params(), &["offset=0"]).unwrap());
+        assert_eq!(Ok(Answer::Int(480)), Day13.solve_with(input, Part2, &ctx));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let input = include_str!("../../../input/2024/examples/13_1.txt");
+        let ctx = Context::default();
+        ctx.cancel.cancel();
+        let error = Error::Cancelled { day: 13, progress: "A pressed 1 times".to_string() };
+        assert_eq!(Err(error), Day13.solve_with(input, Part2, &ctx));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22, Y+\nPrize: X=8400, Y=5400";
+        let error = Error::Parse { day: 13, line: 2, column: 19, text: "".to_string(), message: "expected 2 numbers, found 1".to_string() };
+        assert_eq!(Err(error), Day13.solve(input, Part1));
+    }
+}
@@ -0,0 +1,235 @@
+use super::{Answer, Context, Error, Param, Result, Solver};
+use super::pattern::ints_n;
+use crate::debug;
+
+
+pub struct Day14;
+
+/// Position and velocity of a robot.
+type Robot = ((i32,i32),(i32,i32));
+
+const PARAMS:&[Param] = &[
+    Param::new("seconds", 0, 1_000_000, "seconds the robots move in part 1, 100"),
+    Param::new("limit", 0, 1_000_000, "seconds the robots move in part 2, 100000"),
+    Param::new("width", 1, 10_000, "width of the area, inferred from the robots"),
+    Param::new("height", 1, 10_000, "height of the area, inferred from the robots"),
+];
+
+impl Solver for Day14 {
+    type Input<'a> = Vec<Robot>;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Restroom Redoubt"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        let robots = input.lines().enumerate()
+            .map(|(index, line)| parse(index, line))
+            .collect::<Result<Vec<_>>>()?;
+
+        if robots.is_empty() {
+            return Err(Error::end_of_input(0, "expected at least one robot"));
+        }
+        Ok(robots)
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        let (width, height) = area(input, ctx)?;
+        Ok(part1(input, ctx.params.get("seconds").unwrap_or(100), width, height))
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        let (width, height) = area(input, ctx)?;
+        part2(input, width, height, ctx)
+    }
+}
+
+fn parse(index:usize, line:&str) -> Result<Robot> {
+    let [x, y, dx, dy] = ints_n(index, line)?;
+    Ok(((x, y), (dx, dy)))
+}
+
+/// The width and height from the parameters, or else just large enough for the robots.
+fn area(robots:&[Robot], ctx:&Context) -> Result<(i32,i32)> {
+    let width = ctx.params.get("width").unwrap_or_else(|| robots.iter().map(|((x,_),(_,_))| x + 1).max().unwrap_or(1));
+    let height = ctx.params.get("height").unwrap_or_else(|| robots.iter().map(|((_,y),(_,_))| y + 1).max().unwrap_or(1));
+
+    match robots.iter().find(|((x,y),(_,_))| !(0..width).contains(x) || !(0..height).contains(y)) {
+        Some(((x,y),(_,_))) => Err(Error::solve(&format!("robot at {},{} is outside the {}x{} area", x, y, width, height))),
+        None => Ok((width, height)),
+    }
+}
+
+fn get_quadrant(x:i32,y:i32,width:i32,height:i32) -> Option<usize> {
+    let quadrant_width = width / 2;
+    let quadrant_height = height / 2;
+
+    if x == quadrant_width || y == quadrant_height {
+        return None;
+    }
+
+    let left = x < quadrant_width;
+    let up = y < quadrant_height;
+    let mut quad_no = 0;
+    if !left {
+        quad_no += 1;
+    }
+    if !up {
+        quad_no += 2;
+    }
+    Some(quad_no)
+}
+
+fn part1(robots:&[Robot], seconds:u32, width:i32, height:i32) -> Answer {
+    let mut quad_count = vec![0,0,0,0];
+    for &robot in robots {
+        let ((x,y), (dx,dy)) = robot;
+        let mut last_x = x; 
+        let mut last_y = y; 
+
+        for _ in 0..seconds {
+            last_x = (last_x + dx).rem_euclid(width);
+            last_y = (last_y + dy).rem_euclid(height);
+        }
+
+        match get_quadrant(last_x, last_y, width, height) {
+            Some(quad_no) => {
+                quad_count[quad_no] += 1;
+            },
+            None => {},
+        }
+    }
+
+    quad_count.iter().product::<usize>().into()
+}
+
+
+/// Robot counts per tile, with blanks for empty tiles.
+fn render(map:&[i32], width:i32, height:i32) -> String {
+    let mut text = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let value = map[(y*width + x) as usize];
+            if value == 0 {
+                text.push(' ');
+            } else {
+                text += &value.to_string();
+            }
+        }
+        text.push('\n');
+    }
+    text
+}
+
+fn part2(robots:&[Robot], width:i32, height:i32, ctx:&Context) -> Result<Answer> {
+    let mut robots = robots.to_vec();
+    let seconds:u32 = ctx.params.get("limit").unwrap_or(100_000);
+
+    for second in 0..seconds {
+        ctx.cancel.check(|| format!("second {} of {}", second, seconds))?;
+        for i in 0..robots.len() {
+            let ((x,y), (dx,dy)) = robots[i];
+        
+            let next_x = (x + dx).rem_euclid(width);
+            let next_y = (y + dy).rem_euclid(height);
+
+            robots[i] = ((next_x, next_y),(dx,dy));
+        }
+
+        let mut map:Vec<i32> = Vec::new();
+        for _ in 0..width*height {
+            map.push(0);
+        }
+
+        for robot in robots.iter() {
+            let ((x,y),(_,_)) = *robot;
+            let index: i32 = y*width + x;
+            map[index as usize] += 1;
+        }
+
+        debug!("after {} seconds:\n{}", second + 1, render(&map, width, height));
+    }
+    Ok(Answer::Unsolved)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+    use super::super::Params;
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/14_1.txt");
+
+        assert_eq!(Ok(Answer::Int(12)), Day14.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_14.txt");
+        assert_eq!(Ok(Answer::Int(215476074)), Day14.solve(input, Part1));
+    }
+
+    //#[test]
+    fn _test2() {
+        let input = include_str!("../../../input/2024/examples/14_1.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day14.solve(input, Part2));
+    }
+
+    //#[test]
+    fn _test_part2() {
+        let input = include_str!("../../../input/2024/input_14.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day14.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let ctx = Context::default();
+        ctx.cancel.cancel();
+        let error = Error::Cancelled { day: 14, progress: "second 0 of 100000".to_string() };
+        assert_eq!(Err(error), Day14.solve_with("p=0,4 v=3,-3", Part2, &ctx));
+    }
+
+    #[test]
+    fn test_params() {
+        let input = include_str!("../../../input/2024/examples/14_1.txt");
+        let params = |assignments:&[&str]| Context::default().with_params(Params::parse(Day14.params(), assignments).unwrap());
+        assert_eq!(Ok(Answer::Int(12)), Day14.solve_with(input, Part1, &params(&["seconds=100", "width=11", "height=7"])));
+        // Nothing has moved yet, and nobody starts in the top right quadrant
+        assert_eq!(Ok(Answer::Int(0)), Day14.solve_with(input, Part1, &params(&["seconds=0"])));
+        assert_eq!(Err(Error::Solve { day: 14, message: "robot at 6,3 is outside the 5x7 area".to_string() }),
+            Day14.solve_with(input, Part1, &params(&["width=5"])));
+    }
+
+    #[test]
+    fn test_fast_robots() {
+        // Velocities larger than the area wrap around more than once, one robot ends in each quadrant
+        let input = "p=0,0 v=-7,-7\np=2,2 v=7,7\np=0,2 v=-7,7\np=2,0 v=7,-7";
+        let ctx = Context::default().with_params(Params::parse(Day14.params(), &["seconds=1", "width=3", "height=3"]).unwrap());
+        assert_eq!(Ok(Answer::Int(1)), Day14.solve_with(input, Part1, &ctx));
+        let ctx = Context::default().with_params(Params::parse(Day14.params(), &["seconds=100", "width=3", "height=3"]).unwrap());
+        assert_eq!(Ok(Answer::Int(1)), Day14.solve_with(input, Part1, &ctx));
+        let ctx = Context::default().with_params(Params::parse(Day14.params(), &["limit=10", "width=3", "height=3"]).unwrap());
+        assert_eq!(Ok(Answer::Unsolved), Day14.solve_with(input, Part2, &ctx));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Error::Parse { day: 14, line: 2, column: 13, text: "".to_string(), message: "expected 4 numbers, found 3".to_string() };
+        assert_eq!(Err(error), Day14.solve("p=0,4 v=3,-3\np=6,3 v=-1,x", Part1));
+    }
+}
@@ -0,0 +1,337 @@
+use std::collections::VecDeque;
+
+use super::{Answer, Context, Direction, Error, Grid, Point, Result, Solver};
+use crate::debug;
+
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input<'a> = (Grid<char>, VecDeque<Direction>);
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Warehouse Woes"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        parse_map(input.lines().collect())
+    }
+
+    fn part1(&self, (map, path):&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part1(map.clone(), path.clone()))
+    }
+
+    fn part2(&self, (map, path):&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(part2(widen_map(map), path.clone()))
+    }
+}
+
+
+fn parse_map(lines:Vec<&str>) -> Result<(Grid<char>, VecDeque<Direction>)> {
+    // The map comes first, the moves follow it
+    let map_lines = lines.iter().take_while(|line| line.starts_with('#')).count();
+    let map = Grid::parse_with(&lines[..map_lines].join("\n"), "unexpected character in map", |ch| "#.O@".contains(ch).then_some(ch))?;
+    let mut path = VecDeque::new();
+
+    for (index, line) in lines.iter().enumerate().skip(map_lines) {
+        for (pos, ch) in line.char_indices() {
+            match Direction::from_arrow(ch) {
+                Some(dir) => path.push_back(dir),
+                None => return Err(Error::at(index, line, &line[pos..pos + ch.len_utf8()], "expected a move")),
+            }
+        }
+    }
+
+    if map.find(&'@').is_none() {
+        return Err(Error::end_of_input(lines.len(), "no robot '@' found in the map"));
+    }
+    Ok((map, path))
+}
+
+/// Every tile becomes two tiles side by side.
+fn widen_map(map:&Grid<char>) -> Grid<char> {
+    Grid::from_fn(map.width() * 2, map.height(), |pos| {
+        match (map[Point::new(pos.x / 2, pos.y)], pos.x % 2) {
+            ('@', 1) => '.',
+            ('O', 0) => '[',
+            ('O', _) => ']',
+            (ch, _) => ch,
+        }
+    })
+}
+
+/// The tile at `pos`, outside the map counts as a wall.
+fn tile(map:&Grid<char>, pos:Point) -> char {
+    map.get(pos).copied().unwrap_or('#')
+}
+
+fn get_start_pos(map:&Grid<char>) -> Point {
+    map.find(&'@').expect("the parser checks for the robot")
+}
+
+fn _print_map(map:&Grid<char>) {
+    debug!("map:\n{}", map);
+}
+
+
+fn traverse(map:&mut Grid<char>, mut path:VecDeque<Direction>, start:Point) {
+
+    let mut pos = start;
+
+    while !path.is_empty() {
+        let dir = path.pop_front().unwrap();
+        let next = pos.step(dir);
+        let item = tile(map, next);
+
+        if item == '#' {
+            // We cant move, next diretion
+            continue;
+        } else if item == '.' {
+            // update map
+            map[pos] = '.';
+            map[next] = '@';
+
+            // free space
+            pos = next;
+        } else if item == 'O' {
+            // Box, check if there is free space
+            let mut search = next;
+            loop {
+                let next_item = tile(map, search);
+                if next_item == 'O' {
+                    // Check next
+                    search = search.step(dir);
+                } else if next_item == '#' {
+                    // Unable to push box
+                    break;
+                } else if next_item == '.' {
+                    // Free space, lets push the boxes
+                    map[search] = 'O';
+                    map[next] = '@';
+                    map[pos] = '.';
+                    pos = next;
+                    break;
+                }
+            }
+        }
+    }
+
+
+}
+
+/// Pushes the box whose left half is at `pos` up or down, `check_only` tells if it can move without moving it.
+fn push_box_verfical(check_only:bool, dir:Direction, pos:Point, map:&mut Grid<char>) -> bool {
+
+    //println!("Detected box at:{},{}", x,y);
+
+    // check up left
+    let next = pos.step(dir);
+    
+    let left = tile(map, next);
+    let rigth = tile(map, next.step(Direction::Right));
+    
+    let left_ok = match left {
+        '.' => true,
+        '#' => false,
+        ']' => push_box_verfical(check_only, dir, next.step(Direction::Left), map),
+        '[' => push_box_verfical(check_only, dir, next, map),
+        _ => panic!(".."),
+    };
+
+    let right_ok = left_ok && match rigth {
+        '.' => true,
+        ']' => true,
+        '#' => false,
+        '[' => push_box_verfical(check_only, dir, next.step(Direction::Right), map),
+        _ => panic!("unexpected: left:{}, right:{}", left, rigth),
+    };
+
+    if left_ok && right_ok {
+        // Move boxes
+        if !check_only {
+            map[pos] = '.';
+            map[pos.step(Direction::Right)] = '.';
+
+            map[next] = '[';
+            map[next.step(Direction::Right)] = ']';
+        }
+        return true;
+    } else {
+        return false;
+    }
+
+}
+
+
+/// Pushes the box whose left half is at `pos` left or right.
+fn push_box_horizontal(dir:Direction, pos:Point, map:&mut Grid<char>) -> bool {
+
+    //println!("Detected box at:{},{}", x,y);
+    // check up left
+    let next = if dir == Direction::Left {
+        pos.step(dir)
+    } else {
+        pos + dir.delta() * 2
+    };
+
+    let next_item = tile(map, next);
+    let move_ok = match next_item {
+        '.' => true,
+        '#' => false,
+        ']' => push_box_horizontal(dir, next.step(Direction::Left), map),
+        '[' => push_box_horizontal(dir, next, map),
+        _ => panic!(".."),
+    };
+
+    if move_ok {
+        // Move boxes
+        let right = pos.step(Direction::Right);
+        map[pos] = '.';
+        map[right] = '.';
+        
+        if dir == Direction::Left {
+            map[pos.step(Direction::Left)] = '[';
+            map[pos] = ']';
+        } else {
+            map[right] = '[';
+            map[right.step(Direction::Right)] = ']';
+        }
+
+        true
+    } else {
+        false
+    }
+}
+
+
+fn traverse_part2(map:&mut Grid<char>, mut path:VecDeque<Direction>, start:Point) {
+    let mut pos = start;
+
+    while !path.is_empty() {
+        let dir = path.pop_front().unwrap();
+        let next = pos.step(dir);
+        let item = tile(map, next);
+
+        if item == '#' {
+            // We cant move, next diretion
+            continue;
+        } else if item == '.' {
+            // update map
+            map[pos] = '.';
+            map[next] = '@';
+
+            // update coordinates
+            pos = next;
+        } else if item == '[' || item == ']' {
+            let push_ok = match dir {
+                Direction::Left => push_box_horizontal(dir, next.step(Direction::Left), map),
+                Direction::Right => push_box_horizontal(dir, next, map),
+                _ => {
+                    let box_pos = if item == '[' {
+                        next
+                    } else {
+                        next.step(Direction::Left)
+                    };
+
+                    if push_box_verfical(true, dir, box_pos, map) {
+                        push_box_verfical(false, dir, box_pos, map)
+                    } else {
+                        false
+                    }
+                },
+            };
+
+            if push_ok {
+                // update map
+                map[pos] = '.';
+                map[next] = '@';
+
+                // update coordinates
+                pos = next;
+            }
+        }
+    }
+
+
+}
+
+
+/// Sum of the GPS coordinates of the boxes, `ch` marks a box.
+fn gps_sum(map:&Grid<char>, ch:char) -> i32 {
+    map.find_all(&ch).map(|pos| 100 * pos.y + pos.x).sum()
+}
+
+fn part1(mut map:Grid<char>, path:VecDeque<Direction>) -> Answer {
+    let start = get_start_pos(&map);
+    traverse(&mut map, path, start);
+    gps_sum(&map, 'O').into()
+}
+
+fn part2(mut map:Grid<char>, path:VecDeque<Direction>) -> Answer {
+    let start = get_start_pos(&map);
+    traverse_part2(&mut map, path, start);
+    gps_sum(&map, '[').into()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/15_1.txt");
+        let input2 = include_str!("../../../input/2024/examples/15_2.txt");
+
+        assert_eq!(Ok(Answer::Int(2028)), Day15.solve(input, Part1));
+        assert_eq!(Ok(Answer::Int(10092)), Day15.solve(input2, Part1));
+
+
+    }
+
+    #[test]
+    fn test_open_map() {
+        // Outside the map is a wall, also where the map has none
+        let input = "#@O\n\n>>^v";
+        assert_eq!(Ok(Answer::Int(2)), Day15.solve(input, Part1));
+        assert_eq!(Ok(Answer::Int(4)), Day15.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_15.txt");
+        assert_eq!(Ok(Answer::Int(1406392)), Day15.solve(input, Part1));
+    }
+
+
+
+    #[test]
+    fn test21() {
+
+        let input = include_str!("../../../input/2024/examples/15_3.txt");
+
+        assert_eq!(Ok(Answer::Int(618)), Day15.solve(input, Part2));
+    }
+
+    #[test]
+    fn test22() {
+        let input2 = include_str!("../../../input/2024/examples/15_2.txt");
+
+        assert_eq!(Ok(Answer::Int(9021)), Day15.solve(input2, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_15.txt");
+        assert_eq!(Ok(Answer::Int(1429013)), Day15.solve(input, Part2));
+    }
+}
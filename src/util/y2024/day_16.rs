@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use super::{dijkstra, Answer, Context, Direction, Error, Grid, Point, Result, Search, Solver};
+
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input<'a> = Grid<char>;
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Reindeer Maze"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        parse(input)
+    }
+
+    fn part1(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        part2(input)
+    }
+
+    fn both(&self, input:&Self::Input<'_>, _ctx:&Context) -> Result<(Answer, Answer)> {
+        both(input)
+    }
+}
+
+/// A reindeer on a tile, facing `direction`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub pos:Point,
+    pub direction:Direction,
+}
+
+impl Position {
+    pub fn new(pos:Point, direction:Direction) -> Position {
+        Position{pos,direction}
+    }
+
+    fn forward(&self) -> Position {
+        Position::new(self.pos.step(self.direction), self.direction)
+    }  
+
+    fn turn_left(&self) -> Position {
+        Position::new(self.pos, self.direction.turn_left())
+    }
+    
+    fn turn_right(&self) -> Position {
+        Position::new(self.pos, self.direction.turn_right())
+    }
+}
+
+
+fn parse(input:&str) -> Result<Grid<char>> {
+    let map = Grid::parse_with(input, "unexpected character in maze", |ch| "#.SE".contains(ch).then_some(ch))?;
+    if map.find(&'S').is_none() {
+        return Err(Error::end_of_input(map.height(), "expected the start tile 'S'"));
+    }
+
+    Ok(map)
+}
+
+
+/// Cheapest walk from S, facing east, to E. Returns the score and position at E, and the search with the best score
+/// of every visited position. Outside the map counts as a wall.
+pub fn traverse(map:&Grid<char>) -> Result<((u64, Position), Search<Position>)> {
+    let start = map.find(&'S').ok_or_else(|| Error::solve("no start tile 'S'"))?;
+    let search = dijkstra(Position::new(start, Direction::Right), |current| {
+        let forward = current.forward();
+        let step = map.get(forward.pos).is_some_and(|tile| *tile != '#').then_some((forward, 1));
+        step.into_iter().chain([(current.turn_left(), 1000), (current.turn_right(), 1000)])
+    }, |current| map.get(current.pos) == Some(&'E'));
+
+    match search.goal() {
+        Some(end) => Ok(((search.distance(end).unwrap(), *end), search)),
+        None => Err(Error::solve("no path from S to E")),
+    }
+} 
+
+
+/// All tiles on some cheapest path that ends at `end_pos`, given the search from `traverse`.
+pub fn backtrack(end_pos:Position, search:&Search<Position>) -> HashSet<Point> {
+    search.on_shortest_paths(&end_pos)
+        .into_iter()
+        .map(|position| position.pos)
+        .collect()
+}
+
+
+
+fn part1(map:&Grid<char>) -> Result<Answer> {
+    let ((score,_), _) = traverse(map)?;
+    Ok(score.into())
+}
+
+fn part2(map:&Grid<char>) -> Result<Answer> {
+    let ((_, end_pos), search) = traverse(map)?;
+    let set = backtrack(end_pos, &search);
+    Ok(set.len().into())
+}
+
+fn both(map:&Grid<char>) -> Result<(Answer, Answer)> {
+    let ((score, end_pos), search) = traverse(map)?;
+    let set = backtrack(end_pos, &search);
+    Ok((score.into(), set.len().into()))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/16_1.txt");
+        assert_eq!(Ok(Answer::Int(7036)), Day16.solve(input, Part1));
+    }
+
+    #[test]
+    fn test12() {
+
+        let input = include_str!("../../../input/2024/examples/16_2.txt");
+        assert_eq!(Ok(Answer::Int(11048)), Day16.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_16.txt");
+        assert_eq!(Ok(Answer::Int(83432)), Day16.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+        let input = include_str!("../../../input/2024/examples/16_1.txt");        
+        assert_eq!(Ok(Answer::Int(45)), Day16.solve(input, Part2));
+    }
+
+    #[test]
+    fn test22() {
+
+        let input = include_str!("../../../input/2024/examples/16_2.txt");
+        assert_eq!(Ok(Answer::Int(64)), Day16.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        // 488 too high
+        let input = include_str!("../../../input/2024/input_16.txt");
+        assert_eq!(Ok(Answer::Int(467)), Day16.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_open_maze() {
+        // No walls around it, and S isn't in the bottom left corner
+        assert_eq!(Ok(Answer::Int(1002)), Day16.solve("..E\n.S.", Part1));
+        let error = Error::Parse { day: 16, line: 3, column: 1, text: "".to_string(), message: "expected the start tile 'S'".to_string() };
+        assert_eq!(Err(error), Day16.solve("#.E\n#..", Part1));
+    }
+
+    #[test]
+    fn test_both() {
+        let input = include_str!("../../../input/2024/examples/16_1.txt");
+        assert_eq!(Ok((Answer::Int(7036), Answer::Int(45))), Day16.solve_both(input));
+    }
+}
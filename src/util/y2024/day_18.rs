@@ -0,0 +1,190 @@
+use std::cmp::max;
+
+use super::{bfs, parse_number, Answer, Context, Error, Grid, Param, Point, Result, Solver};
+
+
+pub struct Day18;
+
+/// Where a byte falls, as (x, y).
+type Byte = (usize,usize);
+
+const PARAMS:&[Param] = &[
+    Param::new("bytes", 0, 1_000_000, "bytes that have fallen before the first path is calculated, 1024"),
+    Param::new("size", 1, 1000, "width and height of the memory space, inferred from the bytes"),
+];
+
+impl Solver for Day18 {
+    type Input<'a> = Vec<Byte>;
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "RAM Run"
+    }
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        parse(input.lines().collect())
+    }
+
+    fn part1(&self, coordinates:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        let (map, _) = fall(coordinates, ctx)?;
+        part1(&map)
+    }
+
+    fn part2(&self, coordinates:&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        let (map, remaining) = fall(coordinates, ctx)?;
+        part2(map, remaining)
+    }
+}
+
+fn parse(lines:Vec<&str>) -> Result<Vec<(usize,usize)>> {
+    lines.iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let trimmed = line.trim();
+            match trimmed.split_once(',') {
+                Some((x, y)) => Ok((parse_number(index, line, x)?, parse_number(index, line, y)?)),
+                None => Err(Error::at(index, line, trimmed, "expected a coordinate like 5,4")),
+            }
+        })
+        .collect()
+}
+
+/// The map after the first `bytes` have fallen, and the bytes that are still to fall.
+fn fall<'a>(coordinates:&'a [Byte], ctx:&Context) -> Result<(Grid<char>, &'a [Byte])> {
+    let num_bytes = ctx.params.get("bytes").unwrap_or(1024);
+    if coordinates.len() < num_bytes {
+        return Err(Error::end_of_input(coordinates.len(), &format!("expected at least {} bytes", num_bytes)));
+    }
+
+    let dimension = ctx.params.get("size").unwrap_or_else(|| coordinates.iter()
+        .map(|item| max(item.0, item.1))
+        .max()
+        .unwrap_or(0) + 1);
+    if let Some((x, y)) = coordinates.iter().find(|(x, y)| max(*x, *y) >= dimension) {
+        return Err(Error::solve(&format!("byte {},{} is outside the memory space of size {}", x, y, dimension)));
+    }
+
+    let (coordinates, remaining) = coordinates.split_at(num_bytes);
+    let map = Grid::from_fn(dimension, dimension, |pos| {
+        if coordinates.contains(&(pos.x as usize, pos.y as usize)) {
+            '#'
+        } else {
+            '.'
+        }
+    });
+
+    Ok((map, remaining))
+}
+
+
+/// One shortest path from the top left to the bottom right corner, `None` when the exit is cut off.
+fn shortest_path(map:&Grid<char>) -> Option<Vec<Point>> {
+    let end = Point::new(map.width() as i32 - 1, map.height() as i32 - 1);
+    let search = bfs(Point::ORIGIN, |pos| map.neighbours4(*pos).filter(|next| map[*next] != '#'), |pos| *pos == end);
+    search.path(&end)
+}
+
+
+fn part1(map:&Grid<char>) -> Result<Answer> {
+    let path = shortest_path(map).ok_or_else(|| Error::solve("no path to the exit"))?;
+    let no_steps = path.len() - 1;
+    Ok(no_steps.into())
+}
+
+fn part2(mut map:Grid<char>, remaining:&[(usize,usize)]) -> Result<Answer> {
+    let mut last_optimal_path = vec![];
+    for &next_pos in remaining {
+        let (x,y) = next_pos;
+
+        // Add next byte
+        map[(x, y)] = '#';
+
+        if !last_optimal_path.is_empty() && !last_optimal_path.contains(&Point::new(x as i32, y as i32)) {
+            // This does not affect the path
+            continue;
+        }
+
+        // Calc distance
+        match shortest_path(&map) {
+            Some(path) => {
+                // Found solution
+                last_optimal_path = path;
+                continue;
+            },
+            None => {
+                // No solution
+                return Ok(Answer::Coord(x as i64, y as i64));
+            },
+        }
+
+    }
+    Err(Error::solve("the exit is never cut off"))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+    use super::super::Params;
+
+    fn example_params() -> Context {
+        Context::default().with_params(Params::parse(Day18.params(), &["bytes=12"]).unwrap())
+    }
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/18_1.txt");
+        assert_eq!(Ok(Answer::Int(22)), Day18.solve_with(input, Part1, &example_params()));
+
+
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_18.txt");
+        assert_eq!(Ok(Answer::Int(294)), Day18.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = include_str!("../../../input/2024/examples/18_1.txt");
+
+        assert_eq!(Ok(Answer::Coord(6,1)), Day18.solve_with(input, Part2, &example_params()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_18.txt");
+        assert_eq!(Ok(Answer::Coord(31,22)), Day18.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_params() {
+        let input = include_str!("../../../input/2024/examples/18_1.txt");
+        let ctx = Context::default().with_params(Params::parse(Day18.params(), &["bytes=12", "size=5"]).unwrap());
+        assert_eq!(Err(Error::Solve { day: 18, message: "byte 5,4 is outside the memory space of size 5".to_string() }),
+            Day18.solve_with(input, Part1, &ctx));
+        assert!(Day18.solve(input, Part1).is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Error::Parse { day: 18, line: 2, column: 3, text: "4 junk".to_string(), message: "expected a number".to_string() };
+        assert_eq!(Err(error), Day18.solve("1,2\n5,4 junk", Part1));
+        let error = Error::Parse { day: 18, line: 1, column: 1, text: "5 4".to_string(), message: "expected a coordinate like 5,4".to_string() };
+        assert_eq!(Err(error), Day18.solve("5 4", Part1));
+    }
+}
@@ -1,27 +1,47 @@
 use std::collections::{HashSet, VecDeque};
 
-use super::Part;
+use super::{Answer, Context, Error, Result, Solver};
+use crate::{debug, trace};
 
 
-pub fn solve(input : String, part: Part) -> String {
+pub struct Day19;
 
-    let lines = input.lines().collect();
-    match part {
-        Part::Part1 => part1(lines),
-        Part::Part2 => part2(lines)
+impl Solver for Day19 {
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Linen Layout"
+    }
+
+    fn parse<'a>(&self, input:&'a str) -> Result<Self::Input<'a>> {
+        parse(input.lines().collect())
+    }
+
+    fn part1(&self, (towels, patterns):&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part1(towels, patterns, ctx)
+    }
+
+    fn part2(&self, (towels, patterns):&Self::Input<'_>, ctx:&Context) -> Result<Answer> {
+        part2(towels, patterns, ctx)
     }
 }
 
-fn parse(lines:Vec<&str>) -> (Vec<&str>, Vec<&str>) {
+fn parse(lines:Vec<&str>) -> Result<(Vec<&str>, Vec<&str>)> {
     let mut it = lines.into_iter();
-    let towels:Vec<&str> = it.next().unwrap().split(',')
+    let towels:Vec<&str> = it.next()
+        .ok_or_else(|| Error::end_of_input(0, "expected a list of towels"))?
+        .split(',')
         .map(|item| item.trim())
         .collect();
 
     let patterns = it
         .filter(|line| line.len() > 0)
         .collect();
-    (towels, patterns)
+    Ok((towels, patterns))
 }
 
 fn starts_with(pattern:&str, towel:&str) -> bool {
@@ -38,9 +58,10 @@ fn starts_with(pattern:&str, towel:&str) -> bool {
     true
 }
 
-fn match_pattern(towels:&Vec<&str>, pattern:&str) -> i32 {
+/// The ways to make `pattern` from the towels, stops with an error when `ctx` is cancelled.
+fn match_pattern(towels:&Vec<&str>, pattern:&str, ctx:&Context) -> Result<i32> {
 
-    println!("trying with pattern:{}", pattern);
+    debug!("trying with pattern:{}", pattern);
     let mut permutations = 0;
     let mut stack = VecDeque::new();
     let mut no_solution_index = HashSet::new();
@@ -48,11 +69,12 @@ fn match_pattern(towels:&Vec<&str>, pattern:&str) -> i32 {
     stack.push_back((0, vec![]));
 
     while !stack.is_empty() {
+        ctx.cancel.check(|| format!("pattern {} after {} arrangements", pattern, permutations))?;
         let (pattern_index, used_towels) = stack.pop_front().unwrap();
         let mut found_solution = false; 
 
         if no_solution_index.contains(&pattern_index) {
-            println!("Skip index:{}", pattern_index);
+            trace!("Skip index:{}", pattern_index);
             continue;
         }
 
@@ -93,31 +115,29 @@ fn match_pattern(towels:&Vec<&str>, pattern:&str) -> i32 {
         }
     }
 
-    return permutations;
+    return Ok(permutations);
 
 }
 
-fn part1(lines:Vec<&str>) -> String {
-    let (towels, patterns) = parse(lines);
+fn part1(towels:&Vec<&str>, patterns:&[&str], ctx:&Context) -> Result<Answer> {
     let mut count = 0;
     for pattern in patterns {
-        if match_pattern(&towels, pattern) > 0 {
+        if match_pattern(towels, pattern, ctx)? > 0 {
             count += 1;
             //break;
         }
 
     }
-    count.to_string()
+    Ok(count.into())
 }
 
-fn part2(lines:Vec<&str>) -> String {
-    let (towels, patterns) = parse(lines);
+fn part2(towels:&Vec<&str>, patterns:&[&str], ctx:&Context) -> Result<Answer> {
     let mut count = 0;
     for pattern in patterns {
-        let permutations=  match_pattern(&towels, pattern);
+        let permutations=  match_pattern(towels, pattern, ctx)?;
         count += permutations;
     }
-    count.to_string()
+    Ok(count.into())
 }
 
 
@@ -132,46 +152,37 @@ mod tests {
     #[test]
     fn test1() {
 
-        let input = "r, wr, b, g, bwu, rb, gb, br
-
-brwrr
-bggr
-gbbr
-rrbgbr
-ubwu
-bwurrg
-brgr
-bbrgwb";
-        assert_eq!("6", solve(input.to_string(), Part1));
+        let input = include_str!("../../../input/2024/examples/19_1.txt");
+        assert_eq!(Ok(Answer::Int(6)), Day19.solve(input, Part1));
     }
 
     //#[test]
     fn _test_part1() {
         // 399 => too high
-        let input = include_str!("../../input/input_19.txt");
-        assert_eq!("1", solve(input.to_string(), Part1));
+        let input = include_str!("../../../input/2024/input_19.txt");
+        assert_eq!(Ok(Answer::Int(1)), Day19.solve(input, Part1));
     }
 
     #[test]
     fn test2() {
 
 
-        let input = "r, wr, b, g, bwu, rb, gb, br
-
-brwrr
-bggr
-gbbr
-rrbgbr
-ubwu
-bwurrg
-brgr
-bbrgwb";
-        assert_eq!("16", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/examples/19_1.txt");
+        assert_eq!(Ok(Answer::Int(16)), Day19.solve(input, Part2));
     }
 
     //#[test]
     fn _test_part2() {
-        let input = include_str!("../../input/input_19.txt");
-        assert_eq!("2", solve(input.to_string(), Part2));
+        let input = include_str!("../../../input/2024/input_19.txt");
+        assert_eq!(Ok(Answer::Int(2)), Day19.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let input = include_str!("../../../input/2024/examples/19_1.txt");
+        let ctx = Context::default();
+        ctx.cancel.cancel();
+        let error = Error::Cancelled { day: 19, progress: "pattern brwrr after 0 arrangements".to_string() };
+        assert_eq!(Err(error), Day19.solve_with(input, Part2, &ctx));
     }
 }
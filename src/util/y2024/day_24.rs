@@ -0,0 +1,64 @@
+use super::{Answer, Context, Result, Solver};
+
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input<'a> = ();
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn title(&self) -> &'static str {
+        "Crossed Wires"
+    }
+
+    fn parse<'a>(&self, _input:&'a str) -> Result<Self::Input<'a>> {
+        Ok(())
+    }
+
+    fn part1(&self, _input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(&self, _input:&Self::Input<'_>, _ctx:&Context) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use super::super::Part::{Part1, Part2};
+
+
+    #[test]
+    fn test1() {
+
+        let input = include_str!("../../../input/2024/examples/24_1.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day24.solve(input, Part1));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../../input/2024/input_24.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day24.solve(input, Part1));
+    }
+
+    #[test]
+    fn test2() {
+
+        let input = "";
+        assert_eq!(Ok(Answer::Unsolved), Day24.solve(input, Part2));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../../input/2024/input_24.txt");
+        assert_eq!(Ok(Answer::Unsolved), Day24.solve(input, Part2));
+    }
+}
@@ -0,0 +1,27 @@
+use advent_of_code_2024::y2024::day_16::traverse;
+use advent_of_code_2024::y2024::day_17::OpCodeComputer;
+use advent_of_code_2024::{find_solver, Answer, Context, Grid, Part, Point};
+
+
+#[test]
+fn test_registry() {
+    let solver = find_solver(2024, 1).unwrap();
+    assert_eq!("Historian Hysteria", solver.title());
+    assert_eq!(Ok(Answer::Int(11)), solver.solve("3   4\n4   3\n2   5\n1   3\n3   9\n3   3", Part::Part1));
+}
+
+#[test]
+fn test_traverse() {
+    let map = Grid::parse("#####\n#..E#\n#S..#\n#####").unwrap();
+    let ((score, end), _) = traverse(&map).unwrap();
+    assert_eq!(1003, score);
+    assert_eq!(Point::new(3, 1), end.pos);
+}
+
+#[test]
+fn test_computer() {
+    let lines = vec!["Register A: 10", "Register B: 0", "Register C: 0", "", "Program: 5,0,5,1,5,4"];
+    let mut computer = OpCodeComputer::load(&lines).unwrap();
+    computer.run(&Context::default()).unwrap();
+    assert_eq!(&[0,1,2], computer.output());
+}